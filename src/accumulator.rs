@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use crate::{hash_data, Data, Hash, MerkleMode, MerkleTree, Proof};
+use anyhow::{Error, Result};
+
+/*
+누산기(Accumulator)
+머클 트리에 리프를 추가하거나 바꾸는 변경 묶음을 적용하고
+그 결과로 바뀐 노드들(WitnessUpdate)을 내보낸다.
+증명(Proof)을 들고 있는 쪽은 새 증명을 요청하지 않고
+WitnessUpdate만으로 자기 증명을 새 루트에 맞게 갱신할 수 있다.
+
+트리는 리프 수가 2의 거듭제곱이어야 하므로
+실제 리프 뒤를 빈 노드로 채워서 용량(capacity)을 맞춘다.
+리프 노드는 데이터의 직렬화(길이 8bytes + 데이터)라서 비어 있을 수 없으므로
+빈 데이터 리프와 채워 넣은 자리가 같은 루트를 만들지 않는다.
+용량이 가득 차면 두 배로 늘어나고 트리의 레벨도 하나 올라간다.
+*/
+
+/// 누산기에 적용할 리프 변경
+#[derive(Debug, Clone)]
+pub enum LeafChange {
+    /// 맨 뒤에 새 리프 추가
+    Append(Data),
+    /// 주어진 인덱스의 리프를 새 데이터로 교체
+    Update(usize, Data),
+}
+
+/*
+증명 갱신 데이터
+len: 갱신 후 실제 리프 수
+num_leaves: 갱신 후 트리의 리프 수(용량)
+nodes: 값이 바뀌었거나 새로 생긴 노드 (레벨, 레벨 안의 인덱스, 해시)
+root_hash: 갱신 후 루트 해시
+*/
+#[derive(Debug, Clone)]
+pub struct WitnessUpdate {
+    pub len: usize,
+    pub num_leaves: usize,
+    pub nodes: Vec<(usize, usize, Hash)>,
+    pub root_hash: Hash,
+}
+
+#[derive(Debug)]
+pub struct MerkleAccumulator {
+    leaves: Vec<Data>,
+    tree: MerkleTree,
+}
+
+impl MerkleAccumulator {
+    pub fn new(leaves: Vec<Data>) -> MerkleAccumulator {
+        let tree = padded_tree(&leaves);
+        MerkleAccumulator { leaves, tree }
+    }
+
+    /// 채워 넣은 빈 리프를 제외한 실제 리프 수를 반환
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    pub fn tree(&self) -> &MerkleTree {
+        &self.tree
+    }
+
+    pub fn root_hash(&self) -> Hash {
+        self.tree.root_hash()
+    }
+
+    /// 실제 리프에 대한 증명을 생성
+    pub fn get_proof(&self, leaf_index: usize) -> Result<Proof> {
+        if leaf_index >= self.leaves.len() {
+            return Err(Error::msg("Leaf index outside of accumulator"));
+        }
+        self.tree.get_merkle_proof_by_index(leaf_index)
+    }
+
+    /*
+    변경 묶음을 순서대로 적용하고 트리를 다시 구성
+    이전 트리와 비교해서 달라진 노드만 WitnessUpdate에 담는다.
+    범위를 벗어난 Update가 있으면 아무것도 바꾸지 않고 오류를 반환
+    */
+    pub fn apply_batch(&mut self, changes: Vec<LeafChange>) -> Result<WitnessUpdate> {
+        let mut leaves = self.leaves.clone();
+        for change in changes {
+            match change {
+                LeafChange::Append(data) => leaves.push(data),
+                LeafChange::Update(index, data) => {
+                    let leaf = leaves
+                        .get_mut(index)
+                        .ok_or(Error::msg("Leaf index outside of accumulator"))?;
+                    *leaf = data;
+                }
+            }
+        }
+
+        let tree = padded_tree(&leaves);
+        let mut nodes = vec![];
+        for level in 0..tree.levels {
+            for index in 0..tree.level_width(level) {
+                let hash = tree.node(level, index).unwrap();
+                if self.tree.node(level, index) != Some(hash) {
+                    nodes.push((level, index, hash.clone()));
+                }
+            }
+        }
+        let update = WitnessUpdate {
+            len: leaves.len(),
+            num_leaves: tree.num_leaves(),
+            nodes,
+            root_hash: tree.root_hash(),
+        };
+
+        self.leaves = leaves;
+        self.tree = tree;
+        Ok(update)
+    }
+}
+
+impl Proof {
+    /*
+    WitnessUpdate로 증명을 갱신
+    각 레벨의 형제 노드가 바뀌었으면 새 해시로 교체하고
+    트리가 커져서 생긴 위쪽 레벨의 형제는 WitnessUpdate에서 가져온다.
+    증명 대상 리프 자체가 바뀌었다면 검증할 때 새 데이터를 써야 한다.
+    채워 넣은 자리에 대한 증명은 갱신하지 않는다.
+    */
+    pub fn apply_update(&mut self, update: &WitnessUpdate) -> Result<()> {
+        if update.num_leaves < self.num_leaves {
+            return Err(Error::msg("Accumulator cannot shrink"));
+        }
+        if self.leaf_index >= update.len {
+            return Err(Error::msg("Leaf index outside of accumulator"));
+        }
+        let directions = Proof::expected_directions(self.leaf_index, update.num_leaves, self.mode)
+            .ok_or(Error::msg("Leaf index outside of tree"))?;
+        let changed: HashMap<(usize, usize), &Hash> = update
+            .nodes
+            .iter()
+            .map(|(level, index, hash)| ((*level, *index), hash))
+            .collect();
+
+        let mut hashes = Vec::with_capacity(directions.len());
+        for (level, direction) in directions.into_iter().enumerate() {
+            let sibling = (self.leaf_index >> level) ^ 1;
+            let hash = match changed.get(&(level, sibling)) {
+                Some(hash) => (*hash).clone(),
+                None => self
+                    .hashes
+                    .get(level)
+                    .map(|(_, hash)| hash.clone())
+                    .ok_or(Error::msg("Update is missing a sibling node"))?,
+            };
+            hashes.push((direction, hash));
+        }
+
+        self.num_leaves = update.num_leaves;
+        self.hashes = hashes;
+        Ok(())
+    }
+}
+
+/// 리프 수가 2의 거듭제곱이 되도록 리프 노드 뒤를 빈 노드로 채운 트리
+fn padded_tree(leaves: &[Data]) -> MerkleTree {
+    let capacity = leaves.len().max(1).next_power_of_two();
+    let mut nodes: Vec<Hash> = leaves.iter().map(hash_data).collect();
+    nodes.resize(capacity, Hash::new());
+    MerkleTree::from_leaf_hashes(nodes, MerkleMode::Legacy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify_merkle_proof;
+
+    /*추가와 교체 뒤에 들고 있던 증명을 새 루트에 맞게 갱신하는지 */
+    #[test]
+    fn test_refresh_proofs_after_batch() {
+        let datas: Vec<Data> = (0..3u8).map(|i| vec![i; 4]).collect();
+        let mut acc = MerkleAccumulator::new(datas.clone());
+        let mut proof0 = acc.get_proof(0).unwrap();
+        let mut proof2 = acc.get_proof(2).unwrap();
+
+        let update = acc
+            .apply_batch(vec![
+                LeafChange::Update(1, vec![9; 4]),
+                LeafChange::Append(vec![3; 4]),
+                LeafChange::Append(vec![4; 4]),
+            ])
+            .unwrap();
+        assert_eq!(acc.len(), 5);
+        assert_eq!(update.num_leaves, 8);
+        assert_eq!(update.root_hash, acc.root_hash());
+
+        let root = acc.root_hash();
+        assert!(!verify_merkle_proof(
+            &proof0,
+            &datas[0],
+            0,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+        proof0.apply_update(&update).unwrap();
+        proof2.apply_update(&update).unwrap();
+        assert!(verify_merkle_proof(
+            &proof0,
+            &datas[0],
+            0,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+        assert!(verify_merkle_proof(
+            &proof2,
+            &datas[2],
+            2,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+    }
+
+    /*바뀐 노드만 갱신 데이터에 담기는지 */
+    #[test]
+    fn test_update_contains_only_changed_nodes() {
+        let datas: Vec<Data> = (0..4u8).map(|i| vec![i; 4]).collect();
+        let mut acc = MerkleAccumulator::new(datas);
+        let update = acc
+            .apply_batch(vec![LeafChange::Update(3, vec![7; 4])])
+            .unwrap();
+        //리프 하나, 부모 하나, 루트
+        let positions: Vec<(usize, usize)> =
+            update.nodes.iter().map(|(l, i, _)| (*l, *i)).collect();
+        assert_eq!(positions, vec![(0, 3), (1, 1), (2, 0)]);
+
+        assert!(acc
+            .apply_batch(vec![LeafChange::Update(4, vec![])])
+            .is_err());
+        assert_eq!(acc.len(), 4);
+    }
+
+    /*채워 넣은 자리는 빈 데이터 리프와 구별되고 증명할 수 없는지 */
+    #[test]
+    fn test_padding_is_not_a_leaf() {
+        let datas: Vec<Data> = (0..3u8).map(|i| vec![i; 4]).collect();
+        let acc = MerkleAccumulator::new(datas.clone());
+        let with_empty = MerkleAccumulator::new([datas, vec![Data::new()]].concat());
+        assert_eq!(acc.tree().num_leaves(), 4);
+        assert_ne!(acc.root_hash(), with_empty.root_hash());
+
+        assert!(acc.get_proof(3).is_err());
+        let root = acc.root_hash();
+        let mut padding_proof = acc.tree().get_merkle_proof_by_index(3).unwrap();
+        assert!(!verify_merkle_proof(
+            &padding_proof,
+            &Data::new(),
+            3,
+            4,
+            &root,
+            MerkleMode::Legacy
+        ));
+
+        let mut acc = acc;
+        let update = acc
+            .apply_batch(vec![LeafChange::Update(0, vec![9; 4])])
+            .unwrap();
+        assert_eq!(update.len, 3);
+        assert!(padding_proof.apply_update(&update).is_err());
+    }
+}
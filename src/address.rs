@@ -0,0 +1,438 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::bech32::{self, Variant};
+use crate::error::AddressError;
+use crate::opcodes::{OP_0, OP_1};
+use crate::script::push_data;
+use crate::standard::{self, ScriptType};
+use crate::{hash160, sha256};
+
+/*
+비트코인 주소
+output 스크립트를 사람이 주고받기 쉬운 문자열로 나타낸 것
+legacy(P2PKH, P2SH): 버전 바이트 + 해시 20bytes를 Base58Check로 인코딩 (1..., 3..., m/n..., 2...)
+SegWit, Taproot: hrp + witness 버전 + 프로그램을 Bech32/Bech32m으로 인코딩 (bc1q..., bc1p...)
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Bitcoin,
+    Testnet,
+    Regtest,
+}
+
+impl Network {
+    pub fn name(self) -> &'static str {
+        match self {
+            Network::Bitcoin => "bitcoin",
+            Network::Testnet => "testnet",
+            Network::Regtest => "regtest",
+        }
+    }
+
+    fn p2pkh_prefix(self) -> u8 {
+        match self {
+            Network::Bitcoin => 0x00,
+            Network::Testnet | Network::Regtest => 0x6f,
+        }
+    }
+
+    fn p2sh_prefix(self) -> u8 {
+        match self {
+            Network::Bitcoin => 0x05,
+            Network::Testnet | Network::Regtest => 0xc4,
+        }
+    }
+
+    fn hrp(self) -> &'static str {
+        match self {
+            Network::Bitcoin => "bc",
+            Network::Testnet => "tb",
+            Network::Regtest => "bcrt",
+        }
+    }
+}
+
+/// 주소가 가리키는 output 스크립트 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Payload {
+    PubKeyHash([u8; 20]),
+    ScriptHash([u8; 20]),
+    WitnessProgram { version: u8, program: Vec<u8> },
+}
+
+/// payload는 생성 함수와 주소 문자열, 스크립트 해석으로만 정해져서 witness 버전이 항상 0~16
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    pub network: Network,
+    payload: Payload,
+}
+
+impl Address {
+    pub fn payload(&self) -> &Payload {
+        &self.payload
+    }
+
+    pub fn p2pkh(pubkey: &[u8], network: Network) -> Address {
+        Address {
+            network,
+            payload: Payload::PubKeyHash(hash160(pubkey).try_into().unwrap()),
+        }
+    }
+
+    pub fn p2sh(redeem_script: &[u8], network: Network) -> Address {
+        Address {
+            network,
+            payload: Payload::ScriptHash(hash160(redeem_script).try_into().unwrap()),
+        }
+    }
+
+    /// SegWit은 압축 공개키만 쓸 수 있어서 압축하지 않은 키로 만든 주소는 쓸 수 없다.
+    pub fn p2wpkh(pubkey: &[u8], network: Network) -> Result<Address, AddressError> {
+        if pubkey.len() != 33 {
+            return Err(AddressError::InvalidWitnessPubkeyLength(pubkey.len()));
+        }
+        Ok(Address {
+            network,
+            payload: Payload::WitnessProgram {
+                version: 0,
+                program: hash160(pubkey),
+            },
+        })
+    }
+
+    pub fn p2wsh(witness_script: &[u8], network: Network) -> Address {
+        Address {
+            network,
+            payload: Payload::WitnessProgram {
+                version: 0,
+                program: sha256(witness_script),
+            },
+        }
+    }
+
+    pub fn p2tr(output_key: &[u8; 32], network: Network) -> Address {
+        Address {
+            network,
+            payload: Payload::WitnessProgram {
+                version: 1,
+                program: output_key.to_vec(),
+            },
+        }
+    }
+
+    /// 주소로 나타낼 수 없는 스크립트(P2PK, 멀티시그, NULL_DATA 등)는 오류
+    pub fn from_script(script: &[u8], network: Network) -> Result<Address, AddressError> {
+        let payload = match standard::classify(script) {
+            ScriptType::PubKeyHash(hash) => Payload::PubKeyHash(hash),
+            ScriptType::ScriptHash(hash) => Payload::ScriptHash(hash),
+            ScriptType::WitnessPubKeyHash(hash) => Payload::WitnessProgram {
+                version: 0,
+                program: hash.to_vec(),
+            },
+            ScriptType::WitnessScriptHash(hash) => Payload::WitnessProgram {
+                version: 0,
+                program: hash.to_vec(),
+            },
+            ScriptType::Taproot(key) => Payload::WitnessProgram {
+                version: 1,
+                program: key.to_vec(),
+            },
+            ScriptType::WitnessUnknown { version, program } => {
+                Payload::WitnessProgram { version, program }
+            }
+            _ => return Err(AddressError::UnsupportedScript),
+        };
+        Ok(Address { network, payload })
+    }
+
+    /// 이 주소로 보내는 output의 pk_script
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        match &self.payload {
+            Payload::PubKeyHash(hash) => standard::p2pkh(hash),
+            Payload::ScriptHash(hash) => standard::p2sh(hash),
+            Payload::WitnessProgram { version, program } => {
+                let mut script = vec![match version {
+                    0 => OP_0,
+                    version => OP_1 + version - 1,
+                }];
+                script.extend_from_slice(&push_data(program));
+                script
+            }
+        }
+    }
+
+    /// 다른 네트워크의 주소면 오류
+    /// testnet과 regtest는 legacy 주소 접두사가 같아서 legacy 주소는 둘 다 허용한다.
+    pub fn require_network(self, network: Network) -> Result<Address, AddressError> {
+        let matches = match self.payload {
+            Payload::WitnessProgram { .. } => self.network == network,
+            _ => self.network.p2pkh_prefix() == network.p2pkh_prefix(),
+        };
+        if !matches {
+            return Err(AddressError::NetworkMismatch {
+                expected: network.name(),
+                found: self.network.name(),
+            });
+        }
+        Ok(Address { network, ..self })
+    }
+
+    fn from_base58(encoded: &str) -> Result<Address, AddressError> {
+        let data = crate::base58::decode_check(encoded)?;
+        if data.len() != 21 {
+            return Err(AddressError::InvalidPayloadLength(data.len()));
+        }
+        let hash: [u8; 20] = data[1..].try_into().unwrap();
+        // testnet과 regtest는 구분할 수 없어서 testnet으로 본다.
+        let (network, payload) = match data[0] {
+            0x00 => (Network::Bitcoin, Payload::PubKeyHash(hash)),
+            0x05 => (Network::Bitcoin, Payload::ScriptHash(hash)),
+            0x6f => (Network::Testnet, Payload::PubKeyHash(hash)),
+            0xc4 => (Network::Testnet, Payload::ScriptHash(hash)),
+            version => return Err(AddressError::UnknownVersionByte(version)),
+        };
+        Ok(Address { network, payload })
+    }
+
+    fn from_bech32(encoded: &str) -> Result<Address, AddressError> {
+        let (hrp, data, variant) = bech32::decode(encoded)?;
+        let network = [Network::Bitcoin, Network::Testnet, Network::Regtest]
+            .into_iter()
+            .find(|network| network.hrp() == hrp)
+            .ok_or(AddressError::UnknownHrp(hrp))?;
+        let (&version, program) = data
+            .split_first()
+            .ok_or(AddressError::InvalidBech32Format)?;
+        if version > 16 {
+            return Err(AddressError::InvalidWitnessVersion(version));
+        }
+        let program = bech32::convert_bits(program, 5, 8, false)?;
+        if !(2..=40).contains(&program.len())
+            || (version == 0 && ![20, 32].contains(&program.len()))
+        {
+            return Err(AddressError::InvalidWitnessProgramLength(program.len()));
+        }
+        let expected_variant = if version == 0 {
+            Variant::Bech32
+        } else {
+            Variant::Bech32m
+        };
+        if variant != expected_variant {
+            return Err(AddressError::WrongChecksumVariant(version));
+        }
+        Ok(Address {
+            network,
+            payload: Payload::WitnessProgram { version, program },
+        })
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.payload {
+            Payload::PubKeyHash(hash) => {
+                let data = [&[self.network.p2pkh_prefix()], &hash[..]].concat();
+                f.write_str(&crate::base58::encode_check(&data))
+            }
+            Payload::ScriptHash(hash) => {
+                let data = [&[self.network.p2sh_prefix()], &hash[..]].concat();
+                f.write_str(&crate::base58::encode_check(&data))
+            }
+            Payload::WitnessProgram { version, program } => {
+                let mut data = vec![*version];
+                data.extend(bech32::convert_bits(program, 8, 5, true).unwrap());
+                let variant = if *version == 0 {
+                    Variant::Bech32
+                } else {
+                    Variant::Bech32m
+                };
+                f.write_str(&bech32::encode(self.network.hrp(), &data, variant))
+            }
+        }
+    }
+}
+
+impl FromStr for Address {
+    type Err = AddressError;
+
+    /// 알려진 hrp로 시작하거나 Bech32 체크섬이 맞으면 Bech32, 아니면 Base58Check로 읽는다.
+    fn from_str(s: &str) -> Result<Address, AddressError> {
+        let lower = s.to_ascii_lowercase();
+        let known_hrp = [Network::Bitcoin, Network::Testnet, Network::Regtest]
+            .iter()
+            .any(|network| lower.starts_with(&format!("{}1", network.hrp())));
+        if known_hrp || bech32::decode(s).is_ok() {
+            Address::from_bech32(s)
+        } else {
+            Address::from_base58(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(address: &str, script: &str) {
+        let parsed: Address = address.parse().unwrap();
+        assert_eq!(hex::encode(parsed.script_pubkey()), script, "{}", address);
+        // Bech32 주소는 소문자로 출력한다
+        match parsed.payload {
+            Payload::WitnessProgram { .. } => {
+                assert_eq!(parsed.to_string(), address.to_ascii_lowercase())
+            }
+            _ => assert_eq!(parsed.to_string(), address),
+        }
+        let from_script =
+            Address::from_script(&hex::decode(script).unwrap(), parsed.network).unwrap();
+        assert_eq!(from_script, parsed);
+    }
+
+    #[test]
+    fn test_base58_addresses() {
+        for (address, script) in [
+            (
+                "132F25rTsvBdp9JzLLBHP5mvGY66i1xdiM",
+                "76a914162c5ea71c0b23f5b9022ef047c4a86470a5b07088ac",
+            ),
+            (
+                "33iFwdLuRpW1uK1RTRqsoi8rR4NpDzk66k",
+                "a914162c5ea71c0b23f5b9022ef047c4a86470a5b07087",
+            ),
+            (
+                "mhYCK8wSgwctbFnc3u9fCzzF8XgodR9KHX",
+                "76a914162c5ea71c0b23f5b9022ef047c4a86470a5b07088ac",
+            ),
+        ] {
+            round_trip(address, script);
+        }
+
+        let pubkey = hex::decode("048d5141948c1702e8c95f438815794b87f706a8d4cd2bffad1dc1570971032c9b6042a0431ded2478b5c9cf2d81c124a5e57347a3c63ef0e7716cf54d613ba183").unwrap();
+        assert_eq!(
+            Address::p2pkh(&pubkey, Network::Bitcoin).to_string(),
+            "1QJVDzdqb1VpbDK7uDeyVXy9mR27CJiyhY"
+        );
+        assert_eq!(
+            Address::p2wpkh(&pubkey, Network::Bitcoin),
+            Err(AddressError::InvalidWitnessPubkeyLength(65))
+        );
+        let pubkey =
+            hex::decode("03df154ebfcf29d29cc10d5c2565018bce2d9edbab267c31d2caf44a63056cf99f")
+                .unwrap();
+        assert_eq!(
+            Address::p2pkh(&pubkey, Network::Testnet).to_string(),
+            "mqkhEMH6NCeYjFybv7pvFC22MFeaNT9AQC"
+        );
+    }
+
+    /*BIP173, BIP350 주소 예제 */
+    #[test]
+    fn test_segwit_addresses() {
+        for (address, script) in [
+            ("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4", "0014751e76e8199196d454941c45d1b3a323f1433bd6"),
+            ("tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7", "00201863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262"),
+            ("bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y", "5128751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6"),
+            ("BC1SW50QGDZ25J", "6002751e"),
+            ("bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs", "5210751e76e8199196d454941c45d1b3a323"),
+            ("tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy", "0020000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433"),
+            ("tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c", "5120000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433"),
+            ("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0", "512079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+        ] {
+            round_trip(address, script);
+        }
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        use AddressError::*;
+        for (address, error) in [
+            (
+                "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut",
+                UnknownHrp("tc".to_string()),
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+                WrongChecksumVariant(1),
+            ),
+            (
+                "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+                WrongChecksumVariant(16),
+            ),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh",
+                WrongChecksumVariant(0),
+            ),
+            (
+                "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+                InvalidBech32Character('o'),
+            ),
+            (
+                "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+                InvalidWitnessVersion(17),
+            ),
+            ("bc1pw5dgrnzv", InvalidWitnessProgramLength(1)),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+                InvalidWitnessProgramLength(41),
+            ),
+            (
+                "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P",
+                InvalidWitnessProgramLength(16),
+            ),
+            (
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+                MixedCase,
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+                InvalidPadding,
+            ),
+            (
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+                InvalidPadding,
+            ),
+            ("bc1gmk9yu", InvalidBech32Format),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+                InvalidChecksum,
+            ),
+            ("132F25rTsvBdp9JzLLBHP5mvGY66i1xdiN", InvalidChecksum),
+        ] {
+            assert_eq!(address.parse::<Address>(), Err(error), "{}", address);
+        }
+
+        let bcrt =
+            Address::from_bech32(&bech32::encode("bcrt", &[0; 33], Variant::Bech32)).unwrap();
+        assert_eq!(bcrt.network, Network::Regtest);
+    }
+
+    #[test]
+    fn test_network_and_script_checks() {
+        let mainnet: Address = "132F25rTsvBdp9JzLLBHP5mvGY66i1xdiM".parse().unwrap();
+        assert_eq!(
+            mainnet.clone().require_network(Network::Testnet),
+            Err(AddressError::NetworkMismatch {
+                expected: "testnet",
+                found: "bitcoin",
+            })
+        );
+        assert!(mainnet.require_network(Network::Bitcoin).is_ok());
+
+        let testnet: Address = "mhYCK8wSgwctbFnc3u9fCzzF8XgodR9KHX".parse().unwrap();
+        let regtest = testnet.require_network(Network::Regtest).unwrap();
+        assert_eq!(regtest.network, Network::Regtest);
+
+        let segwit: Address = "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7"
+            .parse()
+            .unwrap();
+        assert!(segwit.require_network(Network::Regtest).is_err());
+
+        let null_data = standard::null_data(b"hello").unwrap();
+        assert_eq!(
+            Address::from_script(&null_data, Network::Bitcoin),
+            Err(AddressError::UnsupportedScript)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::AddressError;
+use crate::sha256d;
+
+/*
+Base58
+0, O, I, l 처럼 헷갈리는 문자를 뺀 58개 문자로 바이트열을 큰 수로 보고 58진법으로 쓴다.
+앞쪽의 0 바이트는 '1'로 하나씩 옮긴다.
+Base58Check는 데이터 뒤에 sha256d 앞 4bytes를 체크섬으로 붙인다.
+*/
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+pub fn encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&byte| byte == 0).count();
+    // 58진법 자릿수, 낮은 자리부터
+    let mut digits: Vec<u8> = vec![];
+    for &byte in &data[zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let mut encoded = "1".repeat(zeros);
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&digit| ALPHABET[digit as usize] as char),
+    );
+    encoded
+}
+
+pub fn decode(encoded: &str) -> Result<Vec<u8>, AddressError> {
+    let zeros = encoded.chars().take_while(|&c| c == '1').count();
+    // 256진법 자릿수, 낮은 자리부터
+    let mut bytes: Vec<u8> = vec![];
+    for c in encoded.chars().skip(zeros) {
+        let value = ALPHABET
+            .iter()
+            .position(|&letter| letter as char == c)
+            .ok_or(AddressError::InvalidBase58Character(c))?;
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let mut decoded = vec![0; zeros];
+    decoded.extend(bytes.iter().rev());
+    Ok(decoded)
+}
+
+pub fn encode_check(data: &[u8]) -> String {
+    let mut payload = data.to_vec();
+    payload.extend_from_slice(&sha256d(data)[..4]);
+    encode(&payload)
+}
+
+/// 체크섬을 확인하고 떼어낸 데이터
+pub fn decode_check(encoded: &str) -> Result<Vec<u8>, AddressError> {
+    let mut payload = decode(encoded)?;
+    if payload.len() < 4 {
+        return Err(AddressError::InvalidPayloadLength(payload.len()));
+    }
+    let checksum = payload.split_off(payload.len() - 4);
+    if sha256d(&payload)[..4] != checksum[..] {
+        return Err(AddressError::InvalidChecksum);
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base58() {
+        for (data, encoded) in [
+            ("", ""),
+            ("61", "2g"),
+            ("626262", "a3gV"),
+            (
+                "73696d706c792061206c6f6e6720737472696e67",
+                "2cFupjhnEsSn59qHXstmK2ffpLv2",
+            ),
+            (
+                "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+                "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+            ),
+            ("00000000000000000000", "1111111111"),
+        ] {
+            let data = hex::decode(data).unwrap();
+            assert_eq!(encode(&data), encoded);
+            assert_eq!(decode(encoded).unwrap(), data);
+        }
+        assert_eq!(
+            decode("0OIl"),
+            Err(AddressError::InvalidBase58Character('0'))
+        );
+
+        let encoded = encode_check(b"hello");
+        assert_eq!(decode_check(&encoded).unwrap(), b"hello");
+        let mut corrupted = decode(&encoded).unwrap();
+        corrupted[0] ^= 1;
+        assert_eq!(
+            decode_check(&encode(&corrupted)),
+            Err(AddressError::InvalidChecksum)
+        );
+        assert_eq!(
+            decode_check("1"),
+            Err(AddressError::InvalidPayloadLength(1))
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::error::PsbtError;
+
+/*
+Base64 (RFC 4648)
+3bytes를 6bit씩 4글자로 쓰고 모자라는 부분은 '='로 채운다.
+PSBT를 주고받을 때 쓰는 형식 (Bitcoin Core의 walletprocesspsbt 등)
+*/
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+pub fn encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push(PAD as char);
+            }
+        }
+    }
+    encoded
+}
+
+/// 패딩까지 맞춘 표준 형식만 받는다.
+pub fn decode(encoded: &str) -> Result<Vec<u8>, PsbtError> {
+    let encoded = encoded.as_bytes();
+    if !encoded.len().is_multiple_of(4) {
+        return Err(PsbtError::InvalidBase64);
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 4 * 3);
+    let chunks = encoded.len() / 4;
+    for (index, chunk) in encoded.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&c| c == PAD).count();
+        // 패딩은 마지막 묶음 끝에 2개까지만
+        if padding > 2 || (padding > 0 && index + 1 != chunks) {
+            return Err(PsbtError::InvalidBase64);
+        }
+        let mut group = 0u32;
+        for &c in &chunk[..4 - padding] {
+            let value = ALPHABET
+                .iter()
+                .position(|&letter| letter == c)
+                .ok_or(PsbtError::InvalidBase64)?;
+            group = group << 6 | value as u32;
+        }
+        group <<= 6 * padding;
+        let bytes = group.to_be_bytes();
+        // 채우고 남은 비트는 0이어야 같은 데이터의 다른 표기가 생기지 않는다.
+        if bytes[4 - padding..].iter().any(|&byte| byte != 0) {
+            return Err(PsbtError::InvalidBase64);
+        }
+        decoded.extend_from_slice(&bytes[1..4 - padding]);
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /*RFC 4648 예제 */
+    #[test]
+    fn test_base64() {
+        for (data, encoded) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(encode(data.as_bytes()), encoded);
+            assert_eq!(decode(encoded).unwrap(), data.as_bytes());
+        }
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&data)).unwrap(), data);
+
+        for invalid in [
+            "Zg=", "Zg===", "Z===", "Zg==Zm9v", "Zh==", "Zm9v!A==", "Zm8 ",
+        ] {
+            assert!(matches!(decode(invalid), Err(PsbtError::InvalidBase64)));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::AddressError;
+
+/*
+Bech32 (BIP173), Bech32m (BIP350)
+사람이 읽는 부분(hrp) + '1' + 5bit 값들을 32개 문자로 쓴 데이터 + 6글자 체크섬
+체크섬은 GF(32) 위의 BCH 코드로 오타를 잡는다. 두 방식은 체크섬 마지막 상수만 다르다.
+SegWit v0 주소는 Bech32, v1 이상(Taproot)은 Bech32m을 쓴다.
+*/
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc830a3;
+/// 전체 문자열 최대 길이
+const MAX_LENGTH: usize = 90;
+const CHECKSUM_LENGTH: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => BECH32_CONST,
+            Variant::Bech32m => BECH32M_CONST,
+        }
+    }
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut checksum = 1u32;
+    for &value in values {
+        let top = checksum >> 25;
+        checksum = (checksum & 0x1ffffff) << 5 ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// hrp 각 문자의 상위 3bit, 0, 하위 5bit
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut values: Vec<u8> = hrp.bytes().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 31));
+    values
+}
+
+/// data는 5bit 값들
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> String {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; CHECKSUM_LENGTH]);
+    let checksum = polymod(&values) ^ variant.constant();
+
+    let mut encoded = format!("{}1", hrp);
+    encoded.extend(data.iter().map(|&value| CHARSET[value as usize] as char));
+    encoded.extend((0..CHECKSUM_LENGTH).map(|i| {
+        let value = (checksum >> (5 * (5 - i))) & 31;
+        CHARSET[value as usize] as char
+    }));
+    encoded
+}
+
+/// (소문자 hrp, 체크섬을 뗀 5bit 데이터, 체크섬 방식)
+pub fn decode(encoded: &str) -> Result<(String, Vec<u8>, Variant), AddressError> {
+    if encoded.len() > MAX_LENGTH {
+        return Err(AddressError::InvalidBech32Format);
+    }
+    if let Some(c) = encoded.chars().find(|c| !(33..=126).contains(&(*c as u32))) {
+        return Err(AddressError::InvalidBech32Character(c));
+    }
+    let has_lower = encoded.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = encoded.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(AddressError::MixedCase);
+    }
+    let encoded = encoded.to_ascii_lowercase();
+
+    let separator = encoded
+        .rfind('1')
+        .ok_or(AddressError::InvalidBech32Format)?;
+    if separator == 0 || separator + 1 + CHECKSUM_LENGTH > encoded.len() {
+        return Err(AddressError::InvalidBech32Format);
+    }
+    let (hrp, rest) = (&encoded[..separator], &encoded[separator + 1..]);
+    let data = rest
+        .chars()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|&letter| letter as char == c)
+                .map(|value| value as u8)
+                .ok_or(AddressError::InvalidBech32Character(c))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(&data);
+    let variant = match polymod(&values) {
+        BECH32_CONST => Variant::Bech32,
+        BECH32M_CONST => Variant::Bech32m,
+        _ => return Err(AddressError::InvalidChecksum),
+    };
+    Ok((
+        hrp.to_string(),
+        data[..data.len() - CHECKSUM_LENGTH].to_vec(),
+        variant,
+    ))
+}
+
+/// from bit 단위 값들을 to bit 단위로 다시 나눈다.
+/// pad가 아니면 남는 비트는 4bit 이하의 0이어야 한다.
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, AddressError> {
+    let mut accumulator = 0u32;
+    let mut bits = 0u32;
+    let max_value = (1u32 << to) - 1;
+    let mut converted = vec![];
+    for &value in data {
+        if (value as u32) >> from != 0 {
+            return Err(AddressError::InvalidPadding);
+        }
+        accumulator = ((accumulator << from) | value as u32) & ((1 << (from + to - 1)) - 1);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            converted.push(((accumulator >> bits) & max_value) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            converted.push(((accumulator << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || (accumulator << (to - bits)) & max_value != 0 {
+        return Err(AddressError::InvalidPadding);
+    }
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /*BIP173, BIP350 체크섬 예제 */
+    #[test]
+    fn test_checksums() {
+        for (encoded, variant) in [
+            ("A12UEL5L", Variant::Bech32),
+            ("a12uel5l", Variant::Bech32),
+            ("an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs", Variant::Bech32),
+            ("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", Variant::Bech32),
+            ("split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w", Variant::Bech32),
+            ("A1LQFN3A", Variant::Bech32m),
+            ("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", Variant::Bech32m),
+            ("split1checkupstagehandshakeupstreamerranterredcaperredlc445v", Variant::Bech32m),
+        ] {
+            let (hrp, data, decoded_variant) = decode(encoded).unwrap();
+            assert_eq!(decoded_variant, variant);
+            assert_eq!(encode(&hrp, &data, variant), encoded.to_ascii_lowercase());
+        }
+
+        assert_eq!(decode("A12UEL5l"), Err(AddressError::MixedCase));
+        assert_eq!(
+            decode("pzry9x0s0muk"),
+            Err(AddressError::InvalidBech32Format)
+        );
+        assert_eq!(
+            decode("1pzry9x0s0muk"),
+            Err(AddressError::InvalidBech32Format)
+        );
+        assert_eq!(
+            decode("x1b4n0q5v"),
+            Err(AddressError::InvalidBech32Character('b'))
+        );
+        assert_eq!(decode("a12uel5m"), Err(AddressError::InvalidChecksum));
+    }
+
+    #[test]
+    fn test_convert_bits() {
+        let data = [0xff, 0x00, 0xab];
+        let five = convert_bits(&data, 8, 5, true).unwrap();
+        assert_eq!(five.len(), 5);
+        assert_eq!(convert_bits(&five, 5, 8, false).unwrap(), data);
+        // 남는 비트가 0이 아니면 실패
+        assert_eq!(
+            convert_bits(&[0x1f], 5, 8, false),
+            Err(AddressError::InvalidPadding)
+        );
+    }
+}
@@ -0,0 +1,624 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crypto::hmac::Hmac;
+use crypto::mac::Mac;
+use crypto::sha2::Sha512;
+use secp256k1::{Scalar, Secp256k1, SecretKey};
+
+use crate::address::{Address, Network};
+use crate::base58;
+use crate::error::{AddressError, KeyError};
+use crate::hash160;
+use crate::keys::{PrivateKey, PublicKey};
+use crate::taproot::XOnlyPublicKey;
+
+/*
+BIP32 HD 지갑
+시드 하나에서 키 트리를 만든다. 확장 키 = 키 + 체인 코드 32bytes
+자식 키: I = HMAC-SHA512(체인 코드, 데이터 || 자식 번호), 자식 키 = 부모 키 + I[..32], 체인 코드 = I[32..]
+자식 번호가 2^31 이상이면 hardened로 데이터에 개인키(0x00 || 개인키)를 넣어서 공개 확장 키로는 유도할 수 없다.
+그 밖에는 압축 공개키를 넣어서 공개 확장 키만으로도 같은 자식 공개키를 얻는다.
+
+직렬화: 버전 4bytes + 깊이 1byte + 부모 fingerprint 4bytes + 자식 번호 4bytes + 체인 코드 32bytes + 키 33bytes을 Base58Check로 인코딩
+*/
+pub const HARDENED: u32 = 1 << 31;
+const MASTER_KEY_SALT: &[u8] = b"Bitcoin seed";
+const EXTENDED_KEY_SIZE: usize = 78;
+const XPRV: u32 = 0x0488ade4;
+const XPUB: u32 = 0x0488b21e;
+const TPRV: u32 = 0x04358394;
+const TPUB: u32 = 0x043587cf;
+
+/// (왼쪽 32bytes, 오른쪽 32bytes)
+fn hmac_sha512(key: &[u8], data: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::new(Sha512::new(), key);
+    mac.input(data);
+    let mut result = [0u8; 64];
+    mac.raw_result(&mut result);
+    (
+        result[..32].try_into().unwrap(),
+        result[32..].try_into().unwrap(),
+    )
+}
+
+/// 자식 번호들, "m/84'/0'/0'/0/1" 꼴 ('나 h가 붙으면 hardened)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DerivationPath(pub Vec<u32>);
+
+impl DerivationPath {
+    pub fn child(&self, index: u32) -> DerivationPath {
+        let mut path = self.0.clone();
+        path.push(index);
+        DerivationPath(path)
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for &index in &self.0 {
+            if index >= HARDENED {
+                write!(f, "/{}'", index - HARDENED)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || KeyError::InvalidDerivationPath(s.to_string());
+        let mut parts = s.split('/');
+        if parts.next() != Some("m") {
+            return Err(invalid());
+        }
+        parts
+            .map(|part| {
+                let (number, hardened) = match part.strip_suffix(['\'', 'h']) {
+                    Some(number) => (number, HARDENED),
+                    None => (part, 0),
+                };
+                match number.parse::<u32>() {
+                    Ok(index) if index < HARDENED => Ok(index | hardened),
+                    _ => Err(invalid()),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(DerivationPath)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPrivateKey {
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    /// 항상 압축 공개키를 쓴다.
+    pub private_key: PrivateKey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    pub network: Network,
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub public_key: PublicKey,
+}
+
+impl ExtendedPrivateKey {
+    pub fn new_master(seed: &[u8], network: Network) -> Result<Self, KeyError> {
+        let (key, chain_code) = hmac_sha512(MASTER_KEY_SALT, seed);
+        Ok(ExtendedPrivateKey {
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+            chain_code,
+            private_key: PrivateKey::new(&key, network, true)?,
+        })
+    }
+
+    pub fn network(&self) -> Network {
+        self.private_key.network
+    }
+
+    /// 공개키 hash160의 앞 4bytes, 자식 키의 부모 fingerprint가 된다.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.to_public().fingerprint()
+    }
+
+    pub fn to_public(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            network: self.network(),
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            public_key: self.private_key.public_key(),
+        }
+    }
+
+    pub fn derive_child(&self, index: u32) -> Result<Self, KeyError> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(KeyError::MaxDepthExceeded)?;
+        let mut data = if index >= HARDENED {
+            let mut data = vec![0];
+            data.extend_from_slice(&self.private_key.secret_bytes());
+            data
+        } else {
+            self.private_key.public_key().to_bytes()
+        };
+        data.extend_from_slice(&index.to_be_bytes());
+        let (tweak, chain_code) = hmac_sha512(&self.chain_code, &data);
+
+        // I[..32]가 곡선 위수 이상이거나 자식 키가 0이면 그 번호는 쓸 수 없다 (확률 2^-127)
+        let tweak = Scalar::from_be_bytes(tweak).map_err(|_| KeyError::InvalidChildKey(index))?;
+        let key = SecretKey::from_slice(&self.private_key.secret_bytes())
+            .expect("private key is always a valid secret")
+            .add_tweak(&tweak)
+            .map_err(|_| KeyError::InvalidChildKey(index))?;
+        Ok(ExtendedPrivateKey {
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            private_key: PrivateKey::new(&key.secret_bytes(), self.network(), true)?,
+        })
+    }
+
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, KeyError> {
+        path.0
+            .iter()
+            .try_fold(*self, |key, &index| key.derive_child(index))
+    }
+
+    /// purpose 방식의 account번 계정, change, index번 주소
+    pub fn address(
+        &self,
+        purpose: Purpose,
+        account: u32,
+        change: bool,
+        index: u32,
+    ) -> Result<Address, KeyError> {
+        let path = purpose.address_path(self.network(), account, change, index)?;
+        Ok(self.derive_path(&path)?.to_public().address(purpose))
+    }
+}
+
+impl ExtendedPublicKey {
+    pub fn fingerprint(&self) -> [u8; 4] {
+        hash160(&self.public_key.to_bytes())[..4]
+            .try_into()
+            .unwrap()
+    }
+
+    /// hardened 자식은 개인키가 있어야 유도할 수 있다.
+    pub fn derive_child(&self, index: u32) -> Result<Self, KeyError> {
+        if index >= HARDENED {
+            return Err(KeyError::HardenedDerivationFromPublic(index));
+        }
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(KeyError::MaxDepthExceeded)?;
+        let mut data = self.public_key.to_bytes();
+        data.extend_from_slice(&index.to_be_bytes());
+        let (tweak, chain_code) = hmac_sha512(&self.chain_code, &data);
+
+        let tweak = Scalar::from_be_bytes(tweak).map_err(|_| KeyError::InvalidChildKey(index))?;
+        let key = secp256k1::PublicKey::from_slice(&self.public_key.to_bytes())
+            .expect("public key is always valid")
+            .add_exp_tweak(&Secp256k1::verification_only(), &tweak)
+            .map_err(|_| KeyError::InvalidChildKey(index))?;
+        Ok(ExtendedPublicKey {
+            network: self.network,
+            depth,
+            parent_fingerprint: self.fingerprint(),
+            child_number: index,
+            chain_code,
+            public_key: PublicKey::from_slice(&key.serialize())?,
+        })
+    }
+
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, KeyError> {
+        path.0
+            .iter()
+            .try_fold(*self, |key, &index| key.derive_child(index))
+    }
+
+    /// 이 키 하나로 만드는 purpose 방식의 주소
+    pub fn address(&self, purpose: Purpose) -> Address {
+        purpose
+            .address(&self.public_key, self.network)
+            .expect("extended keys use compressed public keys")
+    }
+}
+
+/// 버전을 뺀 77bytes
+fn encode_body(
+    depth: u8,
+    parent_fingerprint: &[u8; 4],
+    child_number: u32,
+    chain_code: &[u8; 32],
+    key: &[u8],
+) -> Vec<u8> {
+    let mut data = vec![depth];
+    data.extend_from_slice(parent_fingerprint);
+    data.extend_from_slice(&child_number.to_be_bytes());
+    data.extend_from_slice(chain_code);
+    data.extend_from_slice(key);
+    data
+}
+
+/// (버전, 데이터), 깊이 0인 마스터 키는 부모 fingerprint와 자식 번호가 0이어야 한다.
+fn decode_extended_key(s: &str) -> Result<(u32, Vec<u8>), KeyError> {
+    let data = base58::decode_check(s)?;
+    if data.len() != EXTENDED_KEY_SIZE {
+        return Err(KeyError::InvalidExtendedKeyLength(data.len()));
+    }
+    if data[4] == 0 && data[5..13].iter().any(|&byte| byte != 0) {
+        return Err(KeyError::InvalidMasterKey);
+    }
+    Ok((u32::from_be_bytes(data[..4].try_into().unwrap()), data))
+}
+
+impl fmt::Display for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let version = match self.network() {
+            Network::Bitcoin => XPRV,
+            Network::Testnet | Network::Regtest => TPRV,
+        };
+        let mut data = version.to_be_bytes().to_vec();
+        let mut key = vec![0];
+        key.extend_from_slice(&self.private_key.secret_bytes());
+        data.extend(encode_body(
+            self.depth,
+            &self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &key,
+        ));
+        write!(f, "{}", base58::encode_check(&data))
+    }
+}
+
+/// testnet과 regtest는 같은 버전을 써서 testnet으로 읽는다.
+impl FromStr for ExtendedPrivateKey {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, data) = decode_extended_key(s)?;
+        let network = match version {
+            XPRV => Network::Bitcoin,
+            TPRV => Network::Testnet,
+            _ => return Err(KeyError::UnknownExtendedKeyVersion(version)),
+        };
+        if data[45] != 0 {
+            return Err(KeyError::InvalidSecretKey);
+        }
+        Ok(ExtendedPrivateKey {
+            depth: data[4],
+            parent_fingerprint: data[5..9].try_into().unwrap(),
+            child_number: u32::from_be_bytes(data[9..13].try_into().unwrap()),
+            chain_code: data[13..45].try_into().unwrap(),
+            private_key: PrivateKey::new(data[46..78].try_into().unwrap(), network, true)?,
+        })
+    }
+}
+
+impl fmt::Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let version = match self.network {
+            Network::Bitcoin => XPUB,
+            Network::Testnet | Network::Regtest => TPUB,
+        };
+        let mut data = version.to_be_bytes().to_vec();
+        data.extend(encode_body(
+            self.depth,
+            &self.parent_fingerprint,
+            self.child_number,
+            &self.chain_code,
+            &self.public_key.to_bytes(),
+        ));
+        write!(f, "{}", base58::encode_check(&data))
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, data) = decode_extended_key(s)?;
+        let network = match version {
+            XPUB => Network::Bitcoin,
+            TPUB => Network::Testnet,
+            _ => return Err(KeyError::UnknownExtendedKeyVersion(version)),
+        };
+        Ok(ExtendedPublicKey {
+            network,
+            depth: data[4],
+            parent_fingerprint: data[5..9].try_into().unwrap(),
+            child_number: u32::from_be_bytes(data[9..13].try_into().unwrap()),
+            chain_code: data[13..45].try_into().unwrap(),
+            public_key: PublicKey::from_slice(&data[45..78])?,
+        })
+    }
+}
+
+/*
+BIP44 계정 구조: m / purpose' / coin_type' / account' / change / address_index
+coin_type은 mainnet 0, testnet(regtest) 1, change는 받는 주소 0, 거스름돈 1
+purpose에 따라 같은 경로 구조로 다른 주소를 만든다.
+BIP44: P2PKH, BIP84: P2WPKH, BIP86: 스크립트 트리 없는 P2TR (내부 키 = 공개키)
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Purpose {
+    Bip44,
+    Bip84,
+    Bip86,
+}
+
+impl Purpose {
+    pub fn number(self) -> u32 {
+        match self {
+            Purpose::Bip44 => 44,
+            Purpose::Bip84 => 84,
+            Purpose::Bip86 => 86,
+        }
+    }
+
+    /// m / purpose' / coin_type' / account', account는 2^31보다 작아야 한다.
+    pub fn account_path(self, network: Network, account: u32) -> Result<DerivationPath, KeyError> {
+        if account >= HARDENED {
+            return Err(KeyError::IndexOutOfRange(account));
+        }
+        let coin_type = match network {
+            Network::Bitcoin => 0,
+            Network::Testnet | Network::Regtest => 1,
+        };
+        Ok(DerivationPath(vec![
+            self.number() | HARDENED,
+            coin_type | HARDENED,
+            account | HARDENED,
+        ]))
+    }
+
+    /// index도 hardened가 아닌 자식 번호라서 2^31보다 작아야 한다.
+    pub fn address_path(
+        self,
+        network: Network,
+        account: u32,
+        change: bool,
+        index: u32,
+    ) -> Result<DerivationPath, KeyError> {
+        if index >= HARDENED {
+            return Err(KeyError::IndexOutOfRange(index));
+        }
+        Ok(self
+            .account_path(network, account)?
+            .child(change as u32)
+            .child(index))
+    }
+
+    /// BIP84, BIP86 주소는 압축 공개키로만 만든다.
+    pub fn address(
+        self,
+        public_key: &PublicKey,
+        network: Network,
+    ) -> Result<Address, AddressError> {
+        let pubkey = public_key.to_bytes();
+        match self {
+            Purpose::Bip44 => Ok(Address::p2pkh(&pubkey, network)),
+            Purpose::Bip84 => Address::p2wpkh(&pubkey, network),
+            Purpose::Bip86 => {
+                if !public_key.compressed {
+                    return Err(AddressError::InvalidWitnessPubkeyLength(pubkey.len()));
+                }
+                let internal_key = XOnlyPublicKey::from_slice(&pubkey[1..])
+                    .expect("compressed public key has a valid x coordinate");
+                let (output_key, _) = internal_key.tap_tweak(None);
+                Ok(Address::p2tr(&output_key.serialize(), network))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip39::Mnemonic;
+    use crate::fixtures;
+
+    /*BIP32 예제 1~3: 개인 확장 키 유도, 공개 확장 키 유도, 직렬화 */
+    #[test]
+    fn test_extended_key_vectors() {
+        for fixture in fixtures::EXTENDED_KEYS {
+            let seed = hex::decode(fixture.seed).unwrap();
+            let master = ExtendedPrivateKey::new_master(&seed, Network::Bitcoin).unwrap();
+            for &(path, xprv, xpub) in fixture.paths {
+                let path: DerivationPath = path.parse().unwrap();
+                let key = master.derive_path(&path).unwrap();
+                assert_eq!(key.to_string(), xprv);
+                assert_eq!(key.to_public().to_string(), xpub);
+                assert_eq!(xprv.parse::<ExtendedPrivateKey>().unwrap(), key);
+                assert_eq!(xpub.parse::<ExtendedPublicKey>().unwrap(), key.to_public());
+
+                // 마지막이 hardened가 아니면 부모 공개 확장 키에서도 같은 키가 나온다
+                if let Some((&last, parent)) = path.0.split_last() {
+                    if last < HARDENED {
+                        let parent = master
+                            .derive_path(&DerivationPath(parent.to_vec()))
+                            .unwrap()
+                            .to_public();
+                        assert_eq!(parent.derive_child(last).unwrap(), key.to_public());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_derivation_path() {
+        let path: DerivationPath = "m/84'/0h/0'/1/23".parse().unwrap();
+        assert_eq!(
+            path,
+            DerivationPath(vec![84 | HARDENED, HARDENED, HARDENED, 1, 23])
+        );
+        assert_eq!(path.to_string(), "m/84'/0'/0'/1/23");
+        assert_eq!(
+            "m".parse::<DerivationPath>().unwrap(),
+            DerivationPath(vec![])
+        );
+        for invalid in ["", "84'/0'", "m/", "m/x", "m/2147483648", "m/1''"] {
+            assert_eq!(
+                invalid.parse::<DerivationPath>(),
+                Err(KeyError::InvalidDerivationPath(invalid.to_string()))
+            );
+        }
+
+        let master = ExtendedPrivateKey::new_master(&[0; 16], Network::Testnet).unwrap();
+        assert!(master.to_string().starts_with("tprv"));
+        assert!(master.to_public().to_string().starts_with("tpub"));
+        assert_eq!(
+            master.to_public().derive_child(HARDENED),
+            Err(KeyError::HardenedDerivationFromPublic(HARDENED))
+        );
+        // 공개 확장 키를 개인 확장 키로 읽을 수 없다
+        let xpub = master.to_public().to_string();
+        assert_eq!(
+            xpub.parse::<ExtendedPrivateKey>(),
+            Err(KeyError::UnknownExtendedKeyVersion(TPUB))
+        );
+
+        let deepest = ExtendedPrivateKey {
+            depth: u8::MAX,
+            ..master
+        };
+        assert_eq!(deepest.derive_child(0), Err(KeyError::MaxDepthExceeded));
+        assert_eq!(
+            deepest.to_public().derive_child(0),
+            Err(KeyError::MaxDepthExceeded)
+        );
+    }
+
+    /*BIP32 예제 5: 잘못된 확장 키 */
+    #[test]
+    fn test_invalid_extended_keys() {
+        for &(key, reason) in fixtures::INVALID_EXTENDED_KEYS {
+            assert!(key.parse::<ExtendedPrivateKey>().is_err(), "{reason}");
+            assert!(key.parse::<ExtendedPublicKey>().is_err(), "{reason}");
+        }
+        let (key, _) = fixtures::INVALID_EXTENDED_KEYS[6];
+        assert_eq!(
+            key.parse::<ExtendedPrivateKey>(),
+            Err(KeyError::InvalidMasterKey)
+        );
+        let (key, _) = fixtures::INVALID_EXTENDED_KEYS[9];
+        assert_eq!(
+            key.parse::<ExtendedPublicKey>(),
+            Err(KeyError::InvalidMasterKey)
+        );
+    }
+
+    /*BIP44, BIP84, BIP86 예제 ("abandon ... about", 암호 없음) */
+    #[test]
+    fn test_purpose_addresses() {
+        let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".parse().unwrap();
+        let master =
+            ExtendedPrivateKey::new_master(&mnemonic.to_seed(""), Network::Bitcoin).unwrap();
+        for (purpose, change, index, address) in [
+            (
+                Purpose::Bip44,
+                false,
+                0,
+                "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA",
+            ),
+            (
+                Purpose::Bip84,
+                false,
+                0,
+                "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            ),
+            (
+                Purpose::Bip84,
+                false,
+                1,
+                "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g",
+            ),
+            (
+                Purpose::Bip84,
+                true,
+                0,
+                "bc1q8c6fshw2dlwun7ekn9qwf37cu2rn755upcp6el",
+            ),
+            (
+                Purpose::Bip86,
+                false,
+                0,
+                "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+            ),
+            (
+                Purpose::Bip86,
+                false,
+                1,
+                "bc1p4qhjn9zdvkux4e44uhx8tc55attvtyu358kutcqkudyccelu0was9fqzwh",
+            ),
+            (
+                Purpose::Bip86,
+                true,
+                0,
+                "bc1p3qkhfews2uk44qtvauqyr2ttdsw7svhkl9nkm9s9c3x4ax5h60wqwruhk7",
+            ),
+        ] {
+            assert_eq!(
+                master
+                    .address(purpose, 0, change, index)
+                    .unwrap()
+                    .to_string(),
+                address
+            );
+        }
+
+        // 계정 공개 확장 키만으로도 받는 주소를 만든다
+        let account = master
+            .derive_path(&Purpose::Bip86.account_path(Network::Bitcoin, 0).unwrap())
+            .unwrap();
+        assert_eq!(
+            account.to_string(),
+            "xprv9xgqHN7yz9MwCkxsBPN5qetuNdQSUttZNKw1dcYTV4mkaAFiBVGQziHs3NRSWMkCzvgjEe3n9xV8oYywvM8at9yRqyaZVz6TYYhX98VjsUk"
+        );
+        let receive = account
+            .to_public()
+            .derive_path(&"m/0/0".parse().unwrap())
+            .unwrap();
+        assert_eq!(
+            receive.address(Purpose::Bip86),
+            master.address(Purpose::Bip86, 0, false, 0).unwrap()
+        );
+        assert_eq!(
+            Purpose::Bip84
+                .address_path(Network::Testnet, 2, true, 5)
+                .unwrap()
+                .to_string(),
+            "m/84'/1'/2'/1/5"
+        );
+        // hardened 범위에 걸치는 계정, 주소 번호
+        assert_eq!(
+            Purpose::Bip84.account_path(Network::Bitcoin, HARDENED),
+            Err(KeyError::IndexOutOfRange(HARDENED))
+        );
+        assert_eq!(
+            master.address(Purpose::Bip84, 0, false, HARDENED + 1),
+            Err(KeyError::IndexOutOfRange(HARDENED + 1))
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crypto::hmac::Hmac;
+use crypto::pbkdf2::pbkdf2;
+use crypto::sha2::Sha512;
+
+use crate::error::KeyError;
+use crate::sha256;
+
+/*
+BIP39 니모닉
+엔트로피 128~256bit(32bit 단위) 뒤에 sha256(엔트로피)의 앞 (엔트로피 bit / 32)bit를 체크섬으로 붙이고
+11bit씩 끊어 2048개 단어 중 하나로 쓴다. (12, 15, 18, 21, 24 단어)
+시드: PBKDF2-HMAC-SHA512(니모닉 문장, "mnemonic" + 암호, 2048회) 64bytes
+영어 단어 목록만 지원한다.
+*/
+const ENGLISH: &str = include_str!("bip39_english.txt");
+const PBKDF2_ROUNDS: u32 = 2048;
+const BITS_PER_WORD: usize = 11;
+
+/// 알파벳 순으로 정렬된 2048개 단어
+fn wordlist() -> Vec<&'static str> {
+    ENGLISH.lines().collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mnemonic {
+    entropy: Vec<u8>,
+}
+
+impl Mnemonic {
+    /// 엔트로피는 안전한 난수 생성기에서 얻어야 한다. (16~32bytes, 4bytes 단위)
+    pub fn from_entropy(entropy: &[u8]) -> Result<Mnemonic, KeyError> {
+        if !(16..=32).contains(&entropy.len()) || !entropy.len().is_multiple_of(4) {
+            return Err(KeyError::InvalidEntropyLength(entropy.len()));
+        }
+        Ok(Mnemonic {
+            entropy: entropy.to_vec(),
+        })
+    }
+
+    /// 단어 수, 단어, 체크섬을 확인한다. 단어 사이 공백은 몇 개든 상관없다.
+    pub fn parse(phrase: &str) -> Result<Mnemonic, KeyError> {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if !(12..=24).contains(&words.len()) || !words.len().is_multiple_of(3) {
+            return Err(KeyError::InvalidWordCount(words.len()));
+        }
+        let wordlist = wordlist();
+        // 엔트로피 + 체크섬(최대 8bit) 1byte
+        let mut data = vec![0u8; words.len() * 4 / 3 + 1];
+        for (i, word) in words.iter().enumerate() {
+            let index = wordlist
+                .binary_search(word)
+                .map_err(|_| KeyError::UnknownWord(word.to_string()))?;
+            for bit in 0..BITS_PER_WORD {
+                if (index >> (BITS_PER_WORD - 1 - bit)) & 1 == 1 {
+                    let position = i * BITS_PER_WORD + bit;
+                    data[position / 8] |= 0x80 >> (position % 8);
+                }
+            }
+        }
+        let checksum = data.pop().unwrap();
+        let checksum_bits = words.len() / 3;
+        if sha256(&data)[0] >> (8 - checksum_bits) != checksum >> (8 - checksum_bits) {
+            return Err(KeyError::InvalidMnemonicChecksum);
+        }
+        Ok(Mnemonic { entropy: data })
+    }
+
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    pub fn words(&self) -> Vec<&'static str> {
+        let wordlist = wordlist();
+        let mut data = self.entropy.clone();
+        data.push(sha256(&self.entropy)[0]);
+        (0..self.entropy.len() * 3 / 4)
+            .map(|i| {
+                let index = (0..BITS_PER_WORD).fold(0, |index, bit| {
+                    let position = i * BITS_PER_WORD + bit;
+                    index << 1 | (data[position / 8] >> (7 - position % 8) & 1) as usize
+                });
+                wordlist[index]
+            })
+            .collect()
+    }
+
+    /// 암호는 NFKD 정규화 없이 그대로 쓰므로 ASCII가 아닌 암호는 다른 지갑과 시드가 다를 수 있다.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let mut mac = Hmac::new(Sha512::new(), self.to_string().as_bytes());
+        let salt = format!("mnemonic{}", passphrase);
+        let mut seed = [0u8; 64];
+        pbkdf2(&mut mac, salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        seed
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.words().join(" "))
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mnemonic::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn test_mnemonic_vectors() {
+        for fixture in fixtures::MNEMONICS {
+            let entropy = hex::decode(fixture.entropy).unwrap();
+            let mnemonic = Mnemonic::from_entropy(&entropy).unwrap();
+            assert_eq!(mnemonic.to_string(), fixture.phrase);
+            assert_eq!(fixture.phrase.parse::<Mnemonic>().unwrap(), mnemonic);
+            assert_eq!(hex::encode(mnemonic.to_seed("TREZOR")), fixture.seed);
+        }
+    }
+
+    #[test]
+    fn test_invalid_mnemonics() {
+        assert_eq!(wordlist().len(), 2048);
+        assert_eq!(
+            Mnemonic::from_entropy(&[0; 17]),
+            Err(KeyError::InvalidEntropyLength(17))
+        );
+        assert_eq!(
+            Mnemonic::from_entropy(&[0; 36]),
+            Err(KeyError::InvalidEntropyLength(36))
+        );
+        assert_eq!(
+            Mnemonic::parse("abandon abandon abandon"),
+            Err(KeyError::InvalidWordCount(3))
+        );
+        assert_eq!(
+            Mnemonic::parse(&["abandon"; 12].join(" ")),
+            Err(KeyError::InvalidMnemonicChecksum)
+        );
+        assert_eq!(
+            Mnemonic::parse(&[["abandon"; 11].join(" "), "bitcoins".to_string()].join(" ")),
+            Err(KeyError::UnknownWord("bitcoins".to_string()))
+        );
+        // 공백은 몇 개든 같은 니모닉
+        assert_eq!(
+            Mnemonic::parse(&[["abandon"; 11].join("  "), "about\n".to_string()].join(" "))
+                .unwrap()
+                .entropy(),
+            [0; 16]
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashSet;
+
+use crate::opcodes::{OP_0, OP_1, OP_16};
+use crate::script::{decode_num, encode_num, push_data, Instruction, Instructions};
+use crate::validation::COINBASE_SCRIPT_SIZE;
+use crate::{
+    block_subsidy, witness_commitment, OutPoint, Transaction, TxIn, TxOut, MAX_MONEY,
+    SEQUENCE_FINAL, WITNESS_COMMITMENT_HEADER,
+};
+use anyhow::{Error, Result};
+
+/*
+트랜잭션 빌더
+입력은 사용할 outpoint로, 출력은 pubkey 스크립트와 금액으로 추가하고
+build에서 값이 올바른지 확인한 뒤 Transaction을 만든다.
+tx_in_count, tx_out_count, script_bytes 같은 길이 값은 직접 넣지 않고 목록에서 계산한다.
+
+기본값
+version: 2
+lock_time: 0
+sequence: SEQUENCE_FINAL
+*/
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder {
+    version: i32,
+    lock_time: u32,
+    inputs: Vec<TxIn>,
+    outputs: Vec<TxOut>,
+}
+
+impl Default for TransactionBuilder {
+    fn default() -> Self {
+        TransactionBuilder {
+            version: 2,
+            lock_time: 0,
+            inputs: vec![],
+            outputs: vec![],
+        }
+    }
+}
+
+impl TransactionBuilder {
+    pub fn new() -> TransactionBuilder {
+        TransactionBuilder::default()
+    }
+
+    pub fn version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn lock_time(mut self, lock_time: u32) -> Self {
+        self.lock_time = lock_time;
+        self
+    }
+
+    /// 기본 시퀀스(SEQUENCE_FINAL)로 입력 추가
+    pub fn add_input(self, previous_output: OutPoint) -> Self {
+        self.add_input_with_sequence(previous_output, SEQUENCE_FINAL)
+    }
+
+    /// 시퀀스 번호를 지정해서 입력 추가 (서명 스크립트는 서명할 때 채운다)
+    pub fn add_input_with_sequence(mut self, previous_output: OutPoint, sequence: u32) -> Self {
+        self.inputs.push(TxIn {
+            previous_output,
+            script_bytes: 0,
+            signature_script: vec![],
+            sequence,
+            witness: vec![],
+        });
+        self
+    }
+
+    /// pubkey 스크립트로 value 사토시를 보내는 출력 추가
+    pub fn add_output(mut self, pk_script: Vec<u8>, value: u64) -> Self {
+        self.outputs.push(TxOut {
+            value,
+            pk_script_bytes: pk_script.len(),
+            pk_script,
+        });
+        self
+    }
+
+    /*
+    트랜잭션 생성
+    - 입력과 출력이 하나 이상 있어야 한다.
+    - 같은 outpoint를 두 번 쓸 수 없다.
+    - 출력 금액과 그 합은 MAX_MONEY를 넘을 수 없다.
+    */
+    pub fn build(self) -> Result<Transaction> {
+        if self.inputs.is_empty() {
+            return Err(Error::msg("Transaction has no inputs"));
+        }
+        if self.outputs.is_empty() {
+            return Err(Error::msg("Transaction has no outputs"));
+        }
+        let mut spent = HashSet::new();
+        if !self
+            .inputs
+            .iter()
+            .all(|input| spent.insert(input.previous_output))
+        {
+            return Err(Error::msg("Transaction spends the same outpoint twice"));
+        }
+        let total = self
+            .outputs
+            .iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.value));
+        if total.is_none_or(|total| total > MAX_MONEY) {
+            return Err(Error::msg("Output value out of range"));
+        }
+
+        Ok(Transaction {
+            version: self.version,
+            tx_in_count: self.inputs.len(),
+            tx_in: self.inputs,
+            tx_out_count: self.outputs.len(),
+            tx_out: self.outputs,
+            lock_time: self.lock_time,
+        })
+    }
+}
+
+/*
+코인베이스 빌더
+블록의 첫 트랜잭션을 만든다. 블록 보상(block_subsidy)과 블록에 넣은 트랜잭션들의 수수료를 받는다.
+
+서명 스크립트: 높이 + extranonce
+- 높이는 맨 앞에 스크립트 숫자로 푸시한다. (BIP34, 0~16은 OP_0, OP_1~OP_16)
+- extranonce는 헤더의 nonce를 다 쓴 채굴자가 바꿔 가며 머클 루트를 바꾸는 자리.
+  필요한 크기만큼 0으로 채워서 넘기면 된다. (기본값은 빈 푸시 OP_0)
+- 전체 길이는 2~100bytes
+
+출력
+- add_output으로 정한 금액을 보내고 reward_to 스크립트가 남은 보상을 모두 받는다.
+- SegWit 트랜잭션이 있으면 witness commitment output을 마지막에 붙이고
+  입력 witness에 reserved value(32bytes 0)를 넣는다. (BIP141)
+*/
+#[derive(Debug, Clone)]
+pub struct CoinbaseBuilder {
+    height: u32,
+    extra_nonce: Vec<u8>,
+    fees: u64,
+    outputs: Vec<TxOut>,
+    reward_script: Option<Vec<u8>>,
+    witness_commitment: Option<[u8; 32]>,
+}
+
+impl CoinbaseBuilder {
+    pub fn new(height: u32) -> CoinbaseBuilder {
+        CoinbaseBuilder {
+            height,
+            extra_nonce: vec![],
+            fees: 0,
+            outputs: vec![],
+            reward_script: None,
+            witness_commitment: None,
+        }
+    }
+
+    pub fn extra_nonce(mut self, extra_nonce: Vec<u8>) -> Self {
+        self.extra_nonce = extra_nonce;
+        self
+    }
+
+    /// 블록에 넣은 트랜잭션들의 수수료 합 (사토시)
+    pub fn fees(mut self, fees: u64) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// pubkey 스크립트로 정해진 금액을 보내는 출력 추가
+    pub fn add_output(mut self, pk_script: Vec<u8>, value: u64) -> Self {
+        self.outputs.push(TxOut {
+            value,
+            pk_script_bytes: pk_script.len(),
+            pk_script,
+        });
+        self
+    }
+
+    /// 다른 출력에 보내고 남은 보상을 받을 pubkey 스크립트
+    pub fn reward_to(mut self, pk_script: Vec<u8>) -> Self {
+        self.reward_script = Some(pk_script);
+        self
+    }
+
+    /*
+    코인베이스 뒤에 들어갈 트랜잭션들
+    하나라도 witness가 있으면 witness 머클 루트(코인베이스의 wtxid는 0)와
+    reserved value를 이어 붙인 double-SHA256을 commitment로 쓴다.
+    */
+    pub fn commit_witnesses(mut self, txns: &[Transaction]) -> Self {
+        self.witness_commitment = None;
+        if txns.iter().any(Transaction::is_segwit) {
+            self.witness_commitment = Some(witness_commitment(txns, &[0; 32]));
+        }
+        self
+    }
+
+    /*
+    코인베이스 생성
+    - 서명 스크립트는 2~100bytes
+    - 출력 금액의 합은 보상(블록 보상 + 수수료)을 넘을 수 없다.
+    - 출력이 하나 이상 있어야 한다.
+    */
+    pub fn build(self) -> Result<Transaction> {
+        let mut signature_script = match self.height {
+            0 => vec![OP_0],
+            height @ 1..=16 => vec![OP_1 + height as u8 - 1],
+            height => push_data(&encode_num(height as i64)),
+        };
+        signature_script.extend(push_data(&self.extra_nonce));
+        if !COINBASE_SCRIPT_SIZE.contains(&signature_script.len()) {
+            return Err(Error::msg("Coinbase script must be 2 to 100 bytes"));
+        }
+
+        let reward = block_subsidy(self.height)
+            .checked_add(self.fees)
+            .filter(|&reward| reward <= MAX_MONEY)
+            .ok_or(Error::msg("Fees out of range"))?;
+        let paid = self
+            .outputs
+            .iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.value))
+            .filter(|&paid| paid <= reward)
+            .ok_or(Error::msg("Coinbase outputs exceed block reward"))?;
+
+        let mut outputs = self.outputs;
+        if let Some(pk_script) = self.reward_script {
+            outputs.push(TxOut {
+                value: reward - paid,
+                pk_script_bytes: pk_script.len(),
+                pk_script,
+            });
+        }
+        if outputs.is_empty() {
+            return Err(Error::msg("Coinbase has no outputs"));
+        }
+        let mut witness = vec![];
+        if let Some(commitment) = self.witness_commitment {
+            let pk_script = [&WITNESS_COMMITMENT_HEADER[..], &commitment].concat();
+            outputs.push(TxOut {
+                value: 0,
+                pk_script_bytes: pk_script.len(),
+                pk_script,
+            });
+            witness.push(vec![0; 32]);
+        }
+
+        Ok(Transaction {
+            version: 2,
+            tx_in_count: 1,
+            tx_in: vec![TxIn {
+                previous_output: OutPoint::null(),
+                script_bytes: signature_script.len(),
+                signature_script,
+                sequence: SEQUENCE_FINAL,
+                witness,
+            }],
+            tx_out_count: outputs.len(),
+            tx_out: outputs,
+            lock_time: 0,
+        })
+    }
+}
+
+impl Transaction {
+    /// 코인베이스 서명 스크립트 맨 앞에 푸시된 블록 높이 (BIP34)
+    pub fn coinbase_height(&self) -> Option<u32> {
+        if !self.is_coinbase() {
+            return None;
+        }
+        match Instructions::new(&self.tx_in[0].signature_script).next()? {
+            Ok(Instruction::PushBytes(data)) => u32::try_from(decode_num(data, 5).ok()?).ok(),
+            Ok(Instruction::Op(op @ OP_1..=OP_16)) => Some((op - OP_1 + 1) as u32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Block};
+
+    fn outpoint(n: u8) -> OutPoint {
+        OutPoint {
+            txid: [n; 32],
+            vout: n as u32,
+        }
+    }
+
+    /*설정한 값과 계산된 개수로 트랜잭션이 만들어지는지 */
+    #[test]
+    fn test_build_transaction() {
+        let tx = TransactionBuilder::new()
+            .version(1)
+            .lock_time(500_000)
+            .add_input(outpoint(1))
+            .add_input_with_sequence(outpoint(2), 0xfffffffd)
+            .add_output(vec![0x51], 10_000)
+            .build()
+            .unwrap();
+        assert_eq!(tx.version, 1);
+        assert_eq!(tx.lock_time, 500_000);
+        assert_eq!(tx.tx_in_count, 2);
+        assert_eq!(tx.tx_out_count, 1);
+        assert_eq!(tx.tx_in[0].sequence, SEQUENCE_FINAL);
+        assert_eq!(tx.tx_in[1].sequence, 0xfffffffd);
+        assert_eq!(tx.tx_out[0].pk_script_bytes, 1);
+
+        //합의 직렬화로 다시 읽어도 같은 트랜잭션
+        let decoded: Transaction =
+            crate::encode::deserialize(&crate::encode::serialize(&tx)).unwrap();
+        assert_eq!(decoded.txid(), tx.txid());
+    }
+
+    /*잘못된 트랜잭션은 만들지 않는지 */
+    #[test]
+    fn test_build_rejects_invalid() {
+        assert!(TransactionBuilder::new()
+            .add_output(vec![], 1)
+            .build()
+            .is_err());
+        assert!(TransactionBuilder::new()
+            .add_input(outpoint(1))
+            .build()
+            .is_err());
+        assert!(TransactionBuilder::new()
+            .add_input(outpoint(1))
+            .add_input(outpoint(1))
+            .add_output(vec![], 1)
+            .build()
+            .is_err());
+        assert!(TransactionBuilder::new()
+            .add_input(outpoint(1))
+            .add_output(vec![], MAX_MONEY)
+            .add_output(vec![], 1)
+            .build()
+            .is_err());
+    }
+
+    /*BIP34 높이 인코딩, 실제 블록의 코인베이스 높이 */
+    #[test]
+    fn test_coinbase_height() {
+        for (height, encoded) in [
+            (0, vec![0x00]),
+            (1, vec![0x51]),
+            (16, vec![0x60]),
+            (17, vec![0x01, 0x11]),
+            (128, vec![0x02, 0x80, 0x00]),
+            (180_480, vec![0x03, 0x00, 0xc1, 0x02]),
+        ] {
+            let coinbase = CoinbaseBuilder::new(height)
+                .reward_to(vec![0x51])
+                .build()
+                .unwrap();
+            assert!(coinbase.is_coinbase());
+            //extranonce를 주지 않으면 빈 푸시(OP_0)
+            assert_eq!(
+                coinbase.tx_in[0].signature_script,
+                [encoded, vec![0x00]].concat()
+            );
+            assert_eq!(coinbase.coinbase_height(), Some(height));
+            assert_eq!(coinbase.tx_out[0].value, block_subsidy(height));
+        }
+
+        let block = Block::from_hex(fixtures::TESTNET_BLOCK_180480).unwrap();
+        assert_eq!(block.txns[0].coinbase_height(), Some(180_480));
+        assert_eq!(block.txns[1].coinbase_height(), None);
+        let block = Block::from_hex(fixtures::TESTNET_SEGWIT_BLOCK).unwrap();
+        assert_eq!(block.txns[0].coinbase_height(), Some(924_634));
+    }
+
+    /*보상 나누기, extranonce, witness commitment */
+    #[test]
+    fn test_build_coinbase() {
+        let height = 924_634;
+        assert_eq!(block_subsidy(height), 312_500_000);
+        let coinbase = CoinbaseBuilder::new(height)
+            .extra_nonce(vec![0; 8])
+            .fees(10_000)
+            .add_output(vec![0x52], 100_000)
+            .reward_to(vec![0x51])
+            .build()
+            .unwrap();
+        assert_eq!(coinbase.tx_in[0].script_bytes, 4 + 9);
+        assert_eq!(coinbase.tx_out[0].value, 100_000);
+        assert_eq!(coinbase.tx_out[1].value, 312_510_000 - 100_000);
+        assert!(!coinbase.is_segwit());
+
+        //SegWit 트랜잭션이 있는 블록만 commitment가 필요하다
+        let legacy = Transaction::from_hex(fixtures::TRANSACTIONS[1].hex).unwrap();
+        let segwit = Transaction::from_hex(fixtures::SEGWIT_TRANSACTIONS[0].hex).unwrap();
+        for (txns, committed) in [(vec![legacy.clone()], false), (vec![legacy, segwit], true)] {
+            let coinbase = CoinbaseBuilder::new(height)
+                .reward_to(vec![0x51])
+                .commit_witnesses(&txns)
+                .build()
+                .unwrap();
+            assert_eq!(coinbase.tx_out_count, 1 + committed as usize);
+            assert_eq!(coinbase.is_segwit(), committed);
+            let mut block = Block {
+                tx_count: 1 + txns.len(),
+                txns: [vec![coinbase], txns].concat(),
+                ..Default::default()
+            };
+            assert!(block.check_witness_commitment());
+            if committed {
+                block.txns[2].tx_in[0].witness.push(vec![1]);
+                assert!(!block.check_witness_commitment());
+            }
+        }
+    }
+
+    /*스크립트 길이, 보상을 넘는 출력 */
+    #[test]
+    fn test_build_coinbase_rejects_invalid() {
+        assert!(CoinbaseBuilder::new(1).build().is_err());
+        assert!(CoinbaseBuilder::new(1)
+            .extra_nonce(vec![0; 97])
+            .reward_to(vec![])
+            .build()
+            .is_ok());
+        assert!(CoinbaseBuilder::new(1)
+            .extra_nonce(vec![0; 98])
+            .reward_to(vec![])
+            .build()
+            .is_err());
+        assert!(CoinbaseBuilder::new(0)
+            .fees(1)
+            .add_output(vec![], block_subsidy(0) + 2)
+            .build()
+            .is_err());
+        assert!(CoinbaseBuilder::new(0)
+            .fees(MAX_MONEY)
+            .reward_to(vec![])
+            .build()
+            .is_err());
+        //보상이 0인 높이에서도 수수료는 받는다
+        let coinbase = CoinbaseBuilder::new(64 * 210_000)
+            .fees(1)
+            .reward_to(vec![])
+            .build()
+            .unwrap();
+        assert_eq!(coinbase.tx_out[0].value, 1);
+    }
+}
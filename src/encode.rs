@@ -0,0 +1,531 @@
+use crate::error::BlockchainError;
+use crate::{Block, BlockHeaders, OutPoint, Transaction, TxIn, TxOut};
+
+/// 개수와 길이의 최댓값 (Bitcoin Core의 MAX_SIZE)
+pub const MAX_SIZE: u64 = 0x0200_0000;
+
+/*
+비트코인 합의(consensus) 직렬화
+bincode가 아니라 비트코인 노드와 블록 탐색기가 쓰는 형식
+ - 정수는 리틀 엔디언
+ - 개수와 스크립트 길이는 CompactSize 가변 길이 정수
+ - 해시는 내부 바이트 순서 그대로 32bytes
+*/
+pub trait Encodable {
+    /// 합의 직렬화한 바이트를 writer 뒤에 붙임
+    fn consensus_encode(&self, writer: &mut Vec<u8>);
+}
+
+pub trait Decodable: Sized {
+    /// reader 앞에서부터 읽고 읽은 만큼 reader를 앞으로 옮김
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError>;
+}
+
+/// 값을 합의 직렬화 바이트로 변환
+pub fn serialize<T: Encodable + ?Sized>(value: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    value.consensus_encode(&mut bytes);
+    bytes
+}
+
+/// 합의 직렬화 바이트에서 값을 읽음, 남는 바이트가 있으면 오류
+pub fn deserialize<T: Decodable>(bytes: &[u8]) -> Result<T, BlockchainError> {
+    let mut reader = bytes;
+    let value = T::consensus_decode(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(BlockchainError::TrailingBytes(reader.len()));
+    }
+    Ok(value)
+}
+
+/// hex 문자열에서 값을 읽음, 붙여넣을 때 섞인 앞뒤 공백은 무시
+pub fn deserialize_hex<T: Decodable>(hex: &str) -> Result<T, BlockchainError> {
+    deserialize(&hex::decode(hex.trim())?)
+}
+
+/*
+CompactSize
+0xfc 이하: 1byte
+0xfd + u16, 0xfe + u32, 0xff + u64
+더 짧게 쓸 수 있는 값을 길게 쓴 것(non-canonical)은 거부
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactSize(pub u64);
+
+impl Encodable for CompactSize {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        match self.0 {
+            0..=0xfc => writer.push(self.0 as u8),
+            0xfd..=0xffff => {
+                writer.push(0xfd);
+                writer.extend_from_slice(&(self.0 as u16).to_le_bytes());
+            }
+            0x10000..=0xffff_ffff => {
+                writer.push(0xfe);
+                writer.extend_from_slice(&(self.0 as u32).to_le_bytes());
+            }
+            _ => {
+                writer.push(0xff);
+                writer.extend_from_slice(&self.0.to_le_bytes());
+            }
+        }
+    }
+}
+
+impl Decodable for CompactSize {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        let prefix = read_array::<1>(reader)?[0];
+        let (value, min) = match prefix {
+            0xfd => (u16::from_le_bytes(read_array(reader)?) as u64, 0xfd),
+            0xfe => (u32::from_le_bytes(read_array(reader)?) as u64, 0x10000),
+            0xff => (u64::from_le_bytes(read_array(reader)?), 0x1_0000_0000),
+            small => return Ok(CompactSize(small as u64)),
+        };
+        if value < min {
+            return Err(BlockchainError::InvalidVarInt { prefix, value });
+        }
+        Ok(CompactSize(value))
+    }
+}
+
+/// 고정 길이 바이트를 읽음
+pub(crate) fn read_array<const N: usize>(reader: &mut &[u8]) -> Result<[u8; N], BlockchainError> {
+    if reader.len() < N {
+        return Err(BlockchainError::TruncatedInput {
+            needed: N as u64,
+            remaining: reader.len(),
+        });
+    }
+    let (head, rest) = reader.split_at(N);
+    *reader = rest;
+    Ok(head.try_into().unwrap())
+}
+
+/// 길이(CompactSize)를 앞에 붙인 바이트열을 씀
+pub(crate) fn write_var_bytes(writer: &mut Vec<u8>, bytes: &[u8]) {
+    CompactSize(bytes.len() as u64).consensus_encode(writer);
+    writer.extend_from_slice(bytes);
+}
+
+/// 개수나 길이를 읽고 MAX_SIZE를 넘으면 거부
+fn read_size(reader: &mut &[u8]) -> Result<u64, BlockchainError> {
+    let size = CompactSize::consensus_decode(reader)?.0;
+    if size > MAX_SIZE {
+        return Err(BlockchainError::OversizedCount(size));
+    }
+    Ok(size)
+}
+
+/// 길이(CompactSize)가 앞에 붙은 바이트열을 읽음
+pub(crate) fn read_var_bytes(reader: &mut &[u8]) -> Result<Vec<u8>, BlockchainError> {
+    let len = read_size(reader)?;
+    if (reader.len() as u64) < len {
+        return Err(BlockchainError::TruncatedInput {
+            needed: len,
+            remaining: reader.len(),
+        });
+    }
+    let (head, rest) = reader.split_at(len as usize);
+    *reader = rest;
+    Ok(head.to_vec())
+}
+
+/// 개수(CompactSize)를 앞에 붙인 목록을 씀
+fn write_list<T: Encodable>(writer: &mut Vec<u8>, items: &[T]) {
+    CompactSize(items.len() as u64).consensus_encode(writer);
+    for item in items {
+        item.consensus_encode(writer);
+    }
+}
+
+/// 개수(CompactSize)가 앞에 붙은 목록을 읽음
+/// 개수만 크고 데이터가 없는 입력에 미리 메모리를 잡지 않도록 하나씩 읽어서 넣는다.
+fn read_list<T: Decodable>(reader: &mut &[u8]) -> Result<Vec<T>, BlockchainError> {
+    let count = read_size(reader)?;
+    let mut items = vec![];
+    for _ in 0..count {
+        items.push(T::consensus_decode(reader)?);
+    }
+    Ok(items)
+}
+
+/// 목록 자체도 개수를 앞에 붙여서 직렬화 (예: 이전 output 목록)
+impl<T: Encodable> Encodable for [T] {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        write_list(writer, self);
+    }
+}
+
+impl<T: Decodable> Decodable for Vec<T> {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        read_list(reader)
+    }
+}
+
+impl Encodable for OutPoint {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        writer.extend_from_slice(&self.txid);
+        writer.extend_from_slice(&self.vout.to_le_bytes());
+    }
+}
+
+impl Decodable for OutPoint {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        Ok(OutPoint {
+            txid: read_array(reader)?,
+            vout: u32::from_le_bytes(read_array(reader)?),
+        })
+    }
+}
+
+impl Encodable for TxIn {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        self.previous_output.consensus_encode(writer);
+        write_var_bytes(writer, &self.signature_script);
+        writer.extend_from_slice(&self.sequence.to_le_bytes());
+    }
+}
+
+impl Decodable for TxIn {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        let previous_output = OutPoint::consensus_decode(reader)?;
+        let signature_script = read_var_bytes(reader)?;
+        Ok(TxIn {
+            previous_output,
+            script_bytes: signature_script.len(),
+            signature_script,
+            sequence: u32::from_le_bytes(read_array(reader)?),
+            witness: vec![],
+        })
+    }
+}
+
+impl Encodable for TxOut {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        writer.extend_from_slice(&self.value.to_le_bytes());
+        write_var_bytes(writer, &self.pk_script);
+    }
+}
+
+impl Decodable for TxOut {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        let value = u64::from_le_bytes(read_array(reader)?);
+        let pk_script = read_var_bytes(reader)?;
+        Ok(TxOut {
+            value,
+            pk_script_bytes: pk_script.len(),
+            pk_script,
+        })
+    }
+}
+
+/// BIP144 marker, flag
+const SEGWIT_MARKER: u8 = 0x00;
+const SEGWIT_FLAG: u8 = 0x01;
+
+/*
+트랜잭션
+version(i32) | 입력 수 | 입력들 | 출력 수 | 출력들 | lock_time(u32)
+SegWit 트랜잭션(BIP144)은 version 뒤에 marker(0x00), flag(0x01)를 넣고
+lock_time 앞에 입력마다 witness 스택을 넣는다.
+개수는 tx_in_count/tx_out_count가 아니라 실제 목록 길이로 쓴다.
+*/
+impl Encodable for Transaction {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        encode_transaction(self, writer, self.is_segwit());
+    }
+}
+
+/// witness를 뺀 직렬화 (txid 계산용)
+pub fn serialize_without_witness(tx: &Transaction) -> Vec<u8> {
+    let mut bytes = vec![];
+    encode_transaction(tx, &mut bytes, false);
+    bytes
+}
+
+/// witness 없는 직렬화만 읽음 (PSBT의 unsigned tx처럼 입력이 없을 수도 있는 경우)
+pub fn deserialize_without_witness(bytes: &[u8]) -> Result<Transaction, BlockchainError> {
+    let mut reader = bytes;
+    let version = i32::from_le_bytes(read_array(&mut reader)?);
+    let tx_in: Vec<TxIn> = read_list(&mut reader)?;
+    let tx_out: Vec<TxOut> = read_list(&mut reader)?;
+    let lock_time = u32::from_le_bytes(read_array(&mut reader)?);
+    if !reader.is_empty() {
+        return Err(BlockchainError::TrailingBytes(reader.len()));
+    }
+    Ok(Transaction {
+        version,
+        tx_in_count: tx_in.len(),
+        tx_in,
+        tx_out_count: tx_out.len(),
+        tx_out,
+        lock_time,
+    })
+}
+
+fn encode_transaction(tx: &Transaction, writer: &mut Vec<u8>, with_witness: bool) {
+    writer.extend_from_slice(&tx.version.to_le_bytes());
+    if with_witness {
+        writer.push(SEGWIT_MARKER);
+        writer.push(SEGWIT_FLAG);
+    }
+    write_list(writer, &tx.tx_in);
+    write_list(writer, &tx.tx_out);
+    if with_witness {
+        for input in &tx.tx_in {
+            CompactSize(input.witness.len() as u64).consensus_encode(writer);
+            for item in &input.witness {
+                write_var_bytes(writer, item);
+            }
+        }
+    }
+    writer.extend_from_slice(&tx.lock_time.to_le_bytes());
+}
+
+impl Decodable for Transaction {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        let version = i32::from_le_bytes(read_array(reader)?);
+        // 입력 수 자리에 0x00이 오면 SegWit marker로 본다.
+        let segwit = reader.first() == Some(&SEGWIT_MARKER);
+        if segwit {
+            let [_, flag] = read_array::<2>(reader)?;
+            if flag != SEGWIT_FLAG {
+                return Err(BlockchainError::UnknownSegwitFlag(flag));
+            }
+        }
+        let mut tx_in: Vec<TxIn> = read_list(reader)?;
+        let tx_out: Vec<TxOut> = read_list(reader)?;
+        if segwit {
+            for input in tx_in.iter_mut() {
+                let items = read_size(reader)?;
+                for _ in 0..items {
+                    input.witness.push(read_var_bytes(reader)?);
+                }
+            }
+            // flag가 있는데 witness가 모두 비어 있으면 다른 직렬화가 가능하므로 거부
+            if tx_in.iter().all(|input| input.witness.is_empty()) {
+                return Err(BlockchainError::SuperfluousWitness);
+            }
+        }
+        Ok(Transaction {
+            version,
+            tx_in_count: tx_in.len(),
+            tx_in,
+            tx_out_count: tx_out.len(),
+            tx_out,
+            lock_time: u32::from_le_bytes(read_array(reader)?),
+        })
+    }
+}
+
+/*
+블록헤더 80bytes
+version | 이전 블록 헤더 해시 | 머클루트 | time | nbits | nonce
+*/
+impl Encodable for BlockHeaders {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        writer.extend_from_slice(&self.version.to_le_bytes());
+        writer.extend_from_slice(&self.previous_block_header_hash);
+        writer.extend_from_slice(&self.merkle_root_hash);
+        writer.extend_from_slice(&self.time.to_le_bytes());
+        writer.extend_from_slice(&self.nbits.to_le_bytes());
+        writer.extend_from_slice(&self.nonce.to_le_bytes());
+    }
+}
+
+impl Decodable for BlockHeaders {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        Ok(BlockHeaders {
+            version: i32::from_le_bytes(read_array(reader)?),
+            previous_block_header_hash: read_array(reader)?,
+            merkle_root_hash: read_array(reader)?,
+            time: u32::from_le_bytes(read_array(reader)?),
+            nbits: u32::from_le_bytes(read_array(reader)?),
+            nonce: u32::from_le_bytes(read_array(reader)?),
+        })
+    }
+}
+
+impl Encodable for Block {
+    fn consensus_encode(&self, writer: &mut Vec<u8>) {
+        self.block_header.consensus_encode(writer);
+        write_list(writer, &self.txns);
+    }
+}
+
+impl Decodable for Block {
+    fn consensus_decode(reader: &mut &[u8]) -> Result<Self, BlockchainError> {
+        let block_header = BlockHeaders::consensus_decode(reader)?;
+        let txns: Vec<Transaction> = read_list(reader)?;
+        Ok(Block {
+            block_header,
+            tx_count: txns.len(),
+            txns,
+        })
+    }
+}
+
+impl Transaction {
+    /// 블록 탐색기나 노드 로그의 raw 트랜잭션 hex를 파싱
+    pub fn from_hex(hex: &str) -> Result<Transaction, BlockchainError> {
+        deserialize_hex(hex)
+    }
+}
+
+impl BlockHeaders {
+    /// 80bytes 블록 헤더 hex를 파싱
+    pub fn from_hex(hex: &str) -> Result<BlockHeaders, BlockchainError> {
+        deserialize_hex(hex)
+    }
+}
+
+impl Block {
+    /// raw 블록 hex를 파싱
+    pub fn from_hex(hex: &str) -> Result<Block, BlockchainError> {
+        deserialize_hex(hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, sha256d, to_display_hex};
+
+    /*CompactSize 경계값과 non-canonical 거부 */
+    #[test]
+    fn test_compact_size() {
+        for (value, hex) in [
+            (0xfc, "fc"),
+            (0xfd, "fdfd00"),
+            (0xffff, "fdffff"),
+            (0x10000, "fe00000100"),
+            (0x1_0000_0000, "ff0000000001000000"),
+        ] {
+            let bytes = serialize(&CompactSize(value));
+            assert_eq!(hex::encode(&bytes), hex);
+            assert_eq!(
+                deserialize::<CompactSize>(&bytes).unwrap(),
+                CompactSize(value)
+            );
+        }
+        assert!(deserialize::<CompactSize>(&hex::decode("fd0500").unwrap()).is_err());
+        assert!(deserialize::<CompactSize>(&hex::decode("fe").unwrap()).is_err());
+    }
+
+    /*실제 메인넷 트랜잭션이 바이트 그대로 왕복하는지 */
+    #[test]
+    fn test_mainnet_transactions_round_trip() {
+        for fixture in fixtures::TRANSACTIONS {
+            let raw = hex::decode(fixture.hex).unwrap();
+            let tx: Transaction = deserialize(&raw).unwrap();
+            assert_eq!(tx.tx_in_count, tx.tx_in.len());
+            assert_eq!(serialize(&tx), raw, "{}", fixture.name);
+            assert_eq!(to_display_hex(&sha256d(&raw)), fixture.txid);
+        }
+    }
+
+    /*실제 SegWit 트랜잭션이 witness까지 바이트 그대로 왕복하는지 */
+    #[test]
+    fn test_segwit_transactions_round_trip() {
+        for fixture in fixtures::SEGWIT_TRANSACTIONS {
+            let raw = hex::decode(fixture.hex).unwrap();
+            let tx = Transaction::from_hex(fixture.hex).unwrap();
+            assert!(tx.is_segwit(), "{}", fixture.name);
+            assert!(tx.tx_in.iter().all(|input| !input.witness.is_empty()));
+            assert_eq!(serialize(&tx), raw, "{}", fixture.name);
+            assert_eq!(to_display_hex(&tx.txid()), fixture.txid);
+            assert_eq!(to_display_hex(&tx.wtxid()), fixture.wtxid);
+            assert!(serialize_without_witness(&tx).len() < raw.len());
+        }
+
+        let mut raw = hex::decode(fixtures::SEGWIT_TRANSACTIONS[0].hex).unwrap();
+        raw[5] = 0x02;
+        assert!(matches!(
+            deserialize::<Transaction>(&raw),
+            Err(BlockchainError::UnknownSegwitFlag(0x02))
+        ));
+        // witness 없이 marker/flag만 붙인 트랜잭션
+        let legacy = hex::decode(fixtures::TRANSACTIONS[0].hex).unwrap();
+        let mut flagged = legacy[..4].to_vec();
+        flagged.extend_from_slice(&[0x00, 0x01]);
+        flagged.extend_from_slice(&legacy[4..legacy.len() - 4]);
+        flagged.push(0x00);
+        flagged.extend_from_slice(&legacy[legacy.len() - 4..]);
+        assert!(matches!(
+            deserialize::<Transaction>(&flagged),
+            Err(BlockchainError::SuperfluousWitness)
+        ));
+    }
+
+    /*hex 파싱 오류가 원인별로 구분되는지 */
+    #[test]
+    fn test_from_hex_errors() {
+        let genesis = fixtures::TRANSACTIONS[0].hex;
+        assert!(Transaction::from_hex(&format!(" {}\n", genesis)).is_ok());
+
+        assert!(matches!(
+            Transaction::from_hex("zz"),
+            Err(BlockchainError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            Transaction::from_hex(&genesis[..genesis.len() - 2]),
+            Err(BlockchainError::TruncatedInput {
+                needed: 4,
+                remaining: 3
+            })
+        ));
+        assert!(matches!(
+            Transaction::from_hex(&format!("{}00", genesis)),
+            Err(BlockchainError::TrailingBytes(1))
+        ));
+        // 입력 수를 0xfe + u32::MAX로 바꿈
+        assert!(matches!(
+            Transaction::from_hex(&format!("01000000feffffffff{}", &genesis[10..])),
+            Err(BlockchainError::OversizedCount(0xffff_ffff))
+        ));
+        // 입력 수 1을 3bytes로 씀
+        assert!(matches!(
+            Transaction::from_hex(&format!("01000000fd0100{}", &genesis[10..])),
+            Err(BlockchainError::InvalidVarInt {
+                prefix: 0xfd,
+                value: 1
+            })
+        ));
+
+        let header = BlockHeaders::from_hex(fixtures::BLOCKS[0].header).unwrap();
+        assert_eq!(
+            serialize(&header),
+            hex::decode(fixtures::BLOCKS[0].header).unwrap()
+        );
+        assert!(matches!(
+            BlockHeaders::from_hex(&fixtures::BLOCKS[0].header[..150]),
+            Err(BlockchainError::TruncatedInput { .. })
+        ));
+        assert_eq!(
+            Block::from_hex(fixtures::TESTNET_BLOCK_180480)
+                .unwrap()
+                .tx_count,
+            5
+        );
+    }
+
+    /*블록과 헤더가 바이트 그대로 왕복하는지 */
+    #[test]
+    fn test_block_round_trip() {
+        let raw = hex::decode(fixtures::TESTNET_BLOCK_180480).unwrap();
+        let block: Block = deserialize(&raw).unwrap();
+        assert_eq!(block.tx_count, 5);
+        assert_eq!(serialize(&block), raw);
+        assert_eq!(serialize(&block.block_header), raw[..80].to_vec());
+        assert_eq!(
+            to_display_hex(&sha256d(&raw[..80])),
+            "00000000fd3ceb2404ff07a785c7fdcc76619edc8ed61bd25134eaa22084366a"
+        );
+
+        // 잘린 입력과 남는 바이트는 거부
+        assert!(deserialize::<Block>(&raw[..raw.len() - 1]).is_err());
+        let mut extra = raw;
+        extra.push(0);
+        assert!(deserialize::<Block>(&extra).is_err());
+    }
+}
@@ -4,4 +4,267 @@ use thiserror::Error;
 pub enum BlockchainError {
     #[error("Serialize or Deserialize error")]
     SerializeError(#[from] Box<bincode::ErrorKind>),
+    #[error("Unsupported snapshot version {0}")]
+    UnsupportedSnapshotVersion(u32),
+    #[error("Snapshot integrity check failed")]
+    CorruptedSnapshot,
+    #[error("Snapshot hashes do not match the tree rebuilt from its leaves")]
+    SnapshotRootMismatch,
+    #[error("Invalid hex: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error("Truncated input: needed {needed} more bytes but only {remaining} left")]
+    TruncatedInput { needed: u64, remaining: usize },
+    #[error("{0} trailing bytes after the end of the data")]
+    TrailingBytes(usize),
+    #[error("Count or length {0} exceeds the maximum allowed size")]
+    OversizedCount(u64),
+    #[error("Non-canonical CompactSize: {value} encoded with prefix {prefix:#04x}")]
+    InvalidVarInt { prefix: u8, value: u64 },
+    #[error("Unknown segwit flag {0:#04x}")]
+    UnknownSegwitFlag(u8),
+    #[error("Segwit flag set but every witness is empty")]
+    SuperfluousWitness,
+}
+
+/// 스크립트 실행과 입력 검증 오류
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ScriptError {
+    #[error("Input index {0} out of range")]
+    InputIndexOutOfRange(usize),
+    #[error("Script is larger than the maximum size")]
+    ScriptSize,
+    #[error("Push is larger than the maximum element size")]
+    PushSize,
+    #[error("Operation limit exceeded")]
+    OpCount,
+    #[error("Stack size limit exceeded")]
+    StackSize,
+    #[error("Push runs past the end of the script")]
+    TruncatedPush,
+    #[error("Bad opcode {0:#04x}")]
+    BadOpcode(u8),
+    #[error("Disabled opcode {0:#04x}")]
+    DisabledOpcode(u8),
+    #[error("Unbalanced IF/ELSE/ENDIF")]
+    UnbalancedConditional,
+    #[error("Operation on too few stack items")]
+    InvalidStackOperation,
+    #[error("Operation on an empty alt stack")]
+    InvalidAltstackOperation,
+    #[error("OP_RETURN was executed")]
+    OpReturn,
+    #[error("{0} failed")]
+    VerifyFailed(&'static str),
+    #[error("Script number overflow")]
+    NumberOverflow,
+    #[error("Public key count out of range")]
+    PubkeyCount,
+    #[error("Signature count out of range")]
+    SigCount,
+    #[error("CHECKMULTISIG dummy argument is not empty")]
+    SigNullDummy,
+    #[error("Script evaluated to false")]
+    EvalFalse,
+    #[error("Signature script is not push-only")]
+    SigPushOnly,
+    #[error("Witness program has the wrong length")]
+    WitnessProgramWrongLength,
+    #[error("Witness program witness is empty")]
+    WitnessProgramWitnessEmpty,
+    #[error("Witness does not match the witness program")]
+    WitnessProgramMismatch,
+    #[error("Native witness spend with a non-empty signature script")]
+    WitnessMalleated,
+    #[error("Nested witness spend with a non-canonical signature script")]
+    WitnessMalleatedP2sh,
+    #[error("Witness supplied for a non-witness input")]
+    WitnessUnexpected,
+    #[error("Witness script must leave exactly one stack item")]
+    CleanStack,
+    #[error("Schnorr signature has the wrong size")]
+    SchnorrSigSize,
+    #[error("Schnorr signature has an invalid sighash type")]
+    SchnorrSigHashType,
+    #[error("Schnorr signature check failed")]
+    SchnorrSig,
+    #[error("Tapscript OP_IF argument is not minimal")]
+    TapscriptMinimalIf,
+    #[error("OP_CHECKMULTISIG is not available in tapscript")]
+    TapscriptCheckMultisig,
+    #[error("Tapscript public key is empty")]
+    TapscriptEmptyPubkey,
+    #[error("Tapscript exceeds its signature validation weight")]
+    TapscriptValidationWeight,
+    #[error("Taproot control block has an invalid size")]
+    TaprootControlBlockSize,
+    #[error("Negative lock time")]
+    NegativeLockTime,
+    #[error("Lock time requirement not satisfied")]
+    UnsatisfiedLockTime,
+}
+
+/// 주소 인코딩, 디코딩 오류
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AddressError {
+    #[error("Invalid base58 character {0:?}")]
+    InvalidBase58Character(char),
+    #[error("Invalid bech32 character {0:?}")]
+    InvalidBech32Character(char),
+    #[error("Bech32 string mixes upper and lower case")]
+    MixedCase,
+    #[error("Malformed bech32 string")]
+    InvalidBech32Format,
+    #[error("Checksum mismatch")]
+    InvalidChecksum,
+    #[error("Witness version {0} uses the wrong bech32 checksum variant")]
+    WrongChecksumVariant(u8),
+    #[error("Invalid base58 payload length {0}")]
+    InvalidPayloadLength(usize),
+    #[error("Unknown address version byte {0:#04x}")]
+    UnknownVersionByte(u8),
+    #[error("Unknown human-readable part {0:?}")]
+    UnknownHrp(String),
+    #[error("Invalid witness version {0}")]
+    InvalidWitnessVersion(u8),
+    #[error("Invalid witness program length {0}")]
+    InvalidWitnessProgramLength(usize),
+    #[error("Invalid padding in bech32 data")]
+    InvalidPadding,
+    #[error("Address is for {found}, expected {expected}")]
+    NetworkMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+    #[error("Script has no address form")]
+    UnsupportedScript,
+    #[error("Witness public key must be 33 bytes compressed, got {0} bytes")]
+    InvalidWitnessPubkeyLength(usize),
+}
+
+/// 키, WIF 오류
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KeyError {
+    #[error("Secret key is zero or not below the curve order")]
+    InvalidSecretKey,
+    #[error("Invalid public key encoding")]
+    InvalidPublicKey,
+    #[error("Invalid WIF payload length {0}")]
+    InvalidWifLength(usize),
+    #[error("Unknown WIF version byte {0:#04x}")]
+    UnknownWifVersion(u8),
+    #[error("Invalid base58: {0}")]
+    Base58(#[from] AddressError),
+    #[error("Invalid mnemonic entropy length {0}")]
+    InvalidEntropyLength(usize),
+    #[error("Invalid mnemonic word count {0}")]
+    InvalidWordCount(usize),
+    #[error("Unknown mnemonic word {0:?}")]
+    UnknownWord(String),
+    #[error("Mnemonic checksum mismatch")]
+    InvalidMnemonicChecksum,
+    #[error("Invalid extended key length {0}")]
+    InvalidExtendedKeyLength(usize),
+    #[error("Unknown extended key version {0:#010x}")]
+    UnknownExtendedKeyVersion(u32),
+    #[error("Invalid derivation path {0:?}")]
+    InvalidDerivationPath(String),
+    #[error("Cannot derive hardened child {0} from a public key")]
+    HardenedDerivationFromPublic(u32),
+    #[error("Child {0} is an invalid key")]
+    InvalidChildKey(u32),
+    #[error("Extended key is already at the maximum depth")]
+    MaxDepthExceeded,
+    #[error("Master extended key has a parent fingerprint or child number")]
+    InvalidMasterKey,
+    #[error("Index {0} does not fit below the hardened range")]
+    IndexOutOfRange(u32),
+}
+
+/// PSBT 파싱과 역할별 처리 오류
+#[derive(Debug, Error)]
+pub enum PsbtError {
+    #[error("Invalid PSBT magic bytes")]
+    InvalidMagic,
+    #[error("Invalid base64")]
+    InvalidBase64,
+    #[error("Invalid encoding: {0}")]
+    Encoding(#[from] BlockchainError),
+    #[error("Unsupported PSBT version {0}")]
+    UnsupportedVersion(u32),
+    #[error("Duplicate key {}", hex::encode(.0))]
+    DuplicateKey(Vec<u8>),
+    #[error("Invalid key {}", hex::encode(.0))]
+    InvalidKey(Vec<u8>),
+    #[error("Invalid value for key type {0:#04x}")]
+    InvalidValue(u8),
+    #[error("Missing required field {0}")]
+    MissingField(&'static str),
+    #[error("Field {field} is not allowed in PSBT version {version}")]
+    FieldNotAllowed { field: &'static str, version: u32 },
+    #[error("Unsigned transaction has script sigs or witnesses")]
+    UnsignedTxHasScripts,
+    #[error("Previous transaction of input {0} does not match its outpoint")]
+    PrevTxMismatch(usize),
+    #[error("Inputs require both a height and a time lock time")]
+    LockTimeConflict,
+    #[error("Input index {0} out of range")]
+    InputIndexOutOfRange(usize),
+    #[error("Input {0} has no UTXO")]
+    MissingUtxo(usize),
+    #[error("PSBTs are for different transactions")]
+    TxMismatch,
+    #[error("Input {0} cannot be finalized")]
+    CannotFinalize(usize),
+    #[error("Input {0} is not finalized")]
+    NotFinalized(usize),
+    #[error("Redeem script of input {0} does not match its UTXO")]
+    RedeemScriptMismatch(usize),
+    #[error("Witness script of input {0} does not match its UTXO")]
+    WitnessScriptMismatch(usize),
+    #[error("Internal key and merkle root of input {0} do not match its UTXO")]
+    OutputKeyMismatch(usize),
+    #[error("Key error: {0}")]
+    Key(#[from] KeyError),
+}
+
+/// 수수료 계산 오류
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FeeError {
+    #[error("Expected {expected} spent outputs, got {found}")]
+    PrevoutCount { expected: usize, found: usize },
+    #[error("Spent output of input {0} is unknown")]
+    MissingPrevout(usize),
+    #[error("Value out of range")]
+    ValueOutOfRange,
+    #[error("Outputs ({output}) spend more than the inputs ({input})")]
+    NegativeFee { input: u64, output: u64 },
+}
+
+/// 트랜잭션 자체만 보고 하는 검사(Transaction::check) 오류, 규칙마다 하나
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TxValidationError {
+    #[error("Transaction has no inputs")]
+    NoInputs,
+    #[error("Transaction has no outputs")]
+    NoOutputs,
+    #[error("tx_in_count {count} does not match {actual} inputs")]
+    InputCountMismatch { count: usize, actual: usize },
+    #[error("tx_out_count {count} does not match {actual} outputs")]
+    OutputCountMismatch { count: usize, actual: usize },
+    #[error("script_bytes of input {0} does not match its signature script")]
+    InputScriptLengthMismatch(usize),
+    #[error("pk_script_bytes of output {0} does not match its pubkey script")]
+    OutputScriptLengthMismatch(usize),
+    #[error("Transaction weight without witness {0} exceeds the block weight limit")]
+    Oversize(usize),
+    #[error("Value of output {0} out of range")]
+    OutputValueOutOfRange(usize),
+    #[error("Total output value out of range")]
+    TotalOutputValueOutOfRange,
+    #[error("Input {0} spends the same outpoint as an earlier input")]
+    DuplicateInput(usize),
+    #[error("Coinbase script size {0} is not between 2 and 100 bytes")]
+    CoinbaseScriptSize(usize),
+    #[error("Input {0} spends the null outpoint")]
+    NullPrevout(usize),
 }
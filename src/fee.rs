@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::error::FeeError;
+use crate::{OutPoint, Transaction, TxOut, MAX_MONEY};
+
+/*
+수수료
+입력이 쓰는 이전 output 금액의 합 - 출력 금액의 합
+트랜잭션에는 입력 금액이 없으므로 쓰는 output(prevouts)이나 UTXO 조회를 받아서 계산한다.
+수수료율은 가상 크기(vbyte) 하나당 사토시로 나타낸다. (BIP141)
+*/
+
+/// 아직 쓰지 않은 output 조회 (UTXO 집합, 메모리풀 등)
+pub trait UtxoView {
+    fn utxo(&self, outpoint: &OutPoint) -> Option<&TxOut>;
+}
+
+impl UtxoView for HashMap<OutPoint, TxOut> {
+    fn utxo(&self, outpoint: &OutPoint) -> Option<&TxOut> {
+        self.get(outpoint)
+    }
+}
+
+/// 1000 vbyte당 사토시 (Bitcoin Core CFeeRate처럼 소수점 아래 3자리까지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct FeeRate(pub u64);
+
+impl FeeRate {
+    pub fn from_sat_per_vb(sat_per_vb: u64) -> FeeRate {
+        FeeRate(sat_per_vb * 1000)
+    }
+
+    /// fee를 vsize로 나눈 수수료율 (소수점 아래 셋째 자리에서 버림)
+    pub fn from_fee(fee: u64, vsize: usize) -> FeeRate {
+        if vsize == 0 {
+            return FeeRate(0);
+        }
+        FeeRate((fee as u128 * 1000 / vsize as u128) as u64)
+    }
+
+    pub fn sat_per_vb(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    /// vsize 크기의 트랜잭션에 필요한 수수료 (사토시 미만은 올림)
+    pub fn fee_for(self, vsize: usize) -> u64 {
+        (self.0 as u128 * vsize as u128).div_ceil(1000) as u64
+    }
+}
+
+impl fmt::Display for FeeRate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:03} sat/vB", self.0 / 1000, self.0 % 1000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fee {
+    /// 사토시
+    pub amount: u64,
+    pub vsize: usize,
+    pub rate: FeeRate,
+}
+
+/// 금액 하나하나와 합이 MAX_MONEY를 넘지 않는지 확인하면서 더한다.
+fn sum_values<'a>(outputs: impl IntoIterator<Item = &'a TxOut>) -> Result<u64, FeeError> {
+    outputs.into_iter().try_fold(0u64, |total, output| {
+        if output.value > MAX_MONEY {
+            return Err(FeeError::ValueOutOfRange);
+        }
+        total
+            .checked_add(output.value)
+            .filter(|&total| total <= MAX_MONEY)
+            .ok_or(FeeError::ValueOutOfRange)
+    })
+}
+
+impl Transaction {
+    /// prevouts: 입력마다 쓰는 이전 output (입력 순서대로)
+    pub fn fee(&self, prevouts: &[TxOut]) -> Result<Fee, FeeError> {
+        if prevouts.len() != self.tx_in.len() {
+            return Err(FeeError::PrevoutCount {
+                expected: self.tx_in.len(),
+                found: prevouts.len(),
+            });
+        }
+        let input = sum_values(prevouts)?;
+        let output = sum_values(&self.tx_out)?;
+        let amount = input
+            .checked_sub(output)
+            .ok_or(FeeError::NegativeFee { input, output })?;
+        let vsize = self.vsize();
+        Ok(Fee {
+            amount,
+            vsize,
+            rate: FeeRate::from_fee(amount, vsize),
+        })
+    }
+
+    /// 쓰는 output을 view에서 찾아서 계산
+    pub fn fee_with_view(&self, view: &impl UtxoView) -> Result<Fee, FeeError> {
+        let prevouts = self
+            .tx_in
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                view.utxo(&input.previous_output)
+                    .cloned()
+                    .ok_or(FeeError::MissingPrevout(index))
+            })
+            .collect::<Result<Vec<TxOut>, FeeError>>()?;
+        self.fee(&prevouts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::TransactionBuilder;
+    use crate::fixtures;
+
+    fn output(value: u64) -> TxOut {
+        TxOut {
+            value,
+            pk_script_bytes: 0,
+            pk_script: vec![],
+        }
+    }
+
+    #[test]
+    fn test_fee() {
+        // 세 번째 SegWit 예제: 1 입력, 가상 크기 224 vbyte
+        let tx = Transaction::from_hex(fixtures::SEGWIT_TRANSACTIONS[2].hex).unwrap();
+        let sent = tx.output_value().unwrap();
+        let fee = tx.fee(&[output(sent + 4480)]).unwrap();
+        assert_eq!(fee.amount, 4480);
+        assert_eq!(fee.vsize, 224);
+        assert_eq!(fee.rate, FeeRate::from_sat_per_vb(20));
+        assert_eq!(fee.rate.to_string(), "20.000 sat/vB");
+
+        let fee = tx.fee(&[output(sent + 1000)]).unwrap();
+        assert_eq!(fee.rate.to_string(), "4.464 sat/vB");
+        assert!((fee.rate.sat_per_vb() - 4.464).abs() < 1e-9);
+        assert_eq!(fee.rate.fee_for(224), 1000);
+
+        let view: HashMap<OutPoint, TxOut> =
+            HashMap::from([(tx.tx_in[0].previous_output, output(sent))]);
+        assert_eq!(tx.fee_with_view(&view).unwrap().amount, 0);
+        assert_eq!(
+            tx.fee_with_view(&HashMap::new()),
+            Err(FeeError::MissingPrevout(0))
+        );
+
+        assert_eq!(
+            tx.fee(&[output(sent - 1)]),
+            Err(FeeError::NegativeFee {
+                input: sent - 1,
+                output: sent,
+            })
+        );
+        assert_eq!(
+            tx.fee(&[]),
+            Err(FeeError::PrevoutCount {
+                expected: 1,
+                found: 0,
+            })
+        );
+    }
+
+    /*2100만 BTC를 넘는 금액 */
+    #[test]
+    fn test_fee_value_range() {
+        let spend = |values: &[u64]| {
+            let mut builder = TransactionBuilder::new();
+            for vout in 0..values.len() as u32 {
+                builder = builder.add_input(OutPoint {
+                    txid: [1; 32],
+                    vout,
+                });
+            }
+            let tx = builder.add_output(vec![], 1).build().unwrap();
+            let prevouts: Vec<TxOut> = values.iter().map(|&value| output(value)).collect();
+            tx.fee(&prevouts)
+        };
+        assert_eq!(spend(&[MAX_MONEY]).unwrap().amount, MAX_MONEY - 1);
+        assert_eq!(spend(&[MAX_MONEY + 1]), Err(FeeError::ValueOutOfRange));
+        assert_eq!(spend(&[MAX_MONEY, 1]), Err(FeeError::ValueOutOfRange));
+        assert_eq!(spend(&[u64::MAX, u64::MAX]), Err(FeeError::ValueOutOfRange));
+    }
+}
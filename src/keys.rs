@@ -0,0 +1,360 @@
+use anyhow::{Error, Result};
+use secp256k1::{ecdsa, Message, Secp256k1, SecretKey};
+
+use crate::address::Network;
+use crate::base58;
+use crate::error::KeyError;
+use crate::script::push_data;
+use crate::sighash::SIGHASH_SINGLE;
+use crate::standard::{self, ScriptType};
+use crate::{hash160, Transaction, TxOut};
+
+/*
+secp256k1 ECDSA 키
+개인키: 곡선 위수보다 작은 0이 아닌 256bit 정수
+공개키: 개인키 * G, 압축(0x02/0x03 + x 32bytes) 또는 비압축(0x04 + x + y 64bytes)로 쓴다.
+어느 쪽으로 쓰느냐에 따라 hash160이 달라서 같은 개인키라도 주소가 다르다.
+
+WIF: 버전(mainnet 0x80, testnet 0xef) + 개인키 32bytes (+ 압축 공개키를 쓰면 0x01)을 Base58Check로 인코딩
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivateKey {
+    pub network: Network,
+    /// 공개키를 압축 형식으로 쓰는지
+    pub compressed: bool,
+    key: SecretKey,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicKey {
+    pub compressed: bool,
+    key: secp256k1::PublicKey,
+}
+
+impl PrivateKey {
+    pub fn new(secret: &[u8; 32], network: Network, compressed: bool) -> Result<Self, KeyError> {
+        let key = SecretKey::from_slice(secret).map_err(|_| KeyError::InvalidSecretKey)?;
+        Ok(PrivateKey {
+            network,
+            compressed,
+            key,
+        })
+    }
+
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.key.secret_bytes()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            compressed: self.compressed,
+            key: secp256k1::PublicKey::from_secret_key(&Secp256k1::signing_only(), &self.key),
+        }
+    }
+
+    pub fn to_wif(&self) -> String {
+        let mut data = vec![match self.network {
+            Network::Bitcoin => 0x80,
+            Network::Testnet | Network::Regtest => 0xef,
+        }];
+        data.extend_from_slice(&self.key.secret_bytes());
+        if self.compressed {
+            data.push(0x01);
+        }
+        base58::encode_check(&data)
+    }
+
+    /// testnet과 regtest는 같은 버전 바이트를 써서 testnet으로 읽는다.
+    pub fn from_wif(wif: &str) -> Result<Self, KeyError> {
+        let data = base58::decode_check(wif)?;
+        let compressed = match data.len() {
+            33 => false,
+            34 if data[33] == 0x01 => true,
+            len => return Err(KeyError::InvalidWifLength(len)),
+        };
+        let network = match data[0] {
+            0x80 => Network::Bitcoin,
+            0xef => Network::Testnet,
+            version => return Err(KeyError::UnknownWifVersion(version)),
+        };
+        PrivateKey::new(data[1..33].try_into().unwrap(), network, compressed)
+    }
+
+    /// 메시지 다이제스트에 대한 DER 서명 (RFC6979 nonce, low-S)
+    pub fn sign_ecdsa(&self, digest: [u8; 32]) -> Vec<u8> {
+        let mut signature =
+            Secp256k1::signing_only().sign_ecdsa(&Message::from_digest(digest), &self.key);
+        // 릴레이 정책은 high-S 서명을 받지 않는다 (BIP62)
+        signature.normalize_s();
+        signature.serialize_der().to_vec()
+    }
+}
+
+impl PublicKey {
+    pub fn from_slice(data: &[u8]) -> Result<Self, KeyError> {
+        let key = secp256k1::PublicKey::from_slice(data).map_err(|_| KeyError::InvalidPublicKey)?;
+        Ok(PublicKey {
+            compressed: data.len() == 33,
+            key,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if self.compressed {
+            self.key.serialize().to_vec()
+        } else {
+            self.key.serialize_uncompressed().to_vec()
+        }
+    }
+
+    pub fn pubkey_hash(&self) -> [u8; 20] {
+        hash160(&self.to_bytes()).try_into().unwrap()
+    }
+
+    pub fn verify_ecdsa(&self, digest: [u8; 32], der: &[u8]) -> bool {
+        let Ok(signature) = ecdsa::Signature::from_der(der) else {
+            return false;
+        };
+        Secp256k1::verification_only()
+            .verify_ecdsa(&Message::from_digest(digest), &signature, &self.key)
+            .is_ok()
+    }
+}
+
+impl Transaction {
+    /*
+    입력 서명
+    prev_out이 P2PKH면 signature_script에 <서명> <공개키>를,
+    P2WPKH면 witness에 [서명, 공개키]를 넣는다. 서명 뒤에는 sighash 타입 1byte가 붙는다.
+    서명 해시에는 sighash 타입 4bytes가 들어가므로 1byte로 나타낼 수 없는 타입은 거부한다.
+    legacy SIGHASH_SINGLE에 같은 인덱스의 출력이 없으면 누구나 재사용할 수 있는 "1" 해시에 서명하게 되므로 거부한다.
+    */
+    pub fn sign_input(
+        &mut self,
+        input_index: usize,
+        prev_out: &TxOut,
+        key: &PrivateKey,
+        sighash_type: u32,
+    ) -> Result<()> {
+        let sighash_byte = u8::try_from(sighash_type)
+            .map_err(|_| Error::msg("sighash type must fit in one byte"))?;
+        let pubkey = key.public_key();
+        let pubkey_bytes = pubkey.to_bytes();
+        let script_type = standard::classify(&prev_out.pk_script);
+        let expected_hash = match script_type {
+            ScriptType::PubKeyHash(hash) | ScriptType::WitnessPubKeyHash(hash) => hash,
+            _ => return Err(Error::msg("only P2PKH and P2WPKH outputs can be signed")),
+        };
+        if pubkey.pubkey_hash() != expected_hash {
+            return Err(Error::msg(
+                "key does not match the output's public key hash",
+            ));
+        }
+
+        if let ScriptType::WitnessPubKeyHash(hash) = script_type {
+            // BIP143 정책상 witness에는 압축 공개키만 쓴다.
+            if !pubkey.compressed {
+                return Err(Error::msg("P2WPKH requires a compressed public key"));
+            }
+            let script_code = standard::p2pkh(&hash);
+            let sighash =
+                self.segwit_v0_sighash(input_index, &script_code, prev_out.value, sighash_type)?;
+            let mut signature = key.sign_ecdsa(sighash);
+            signature.push(sighash_byte);
+            let input = &mut self.tx_in[input_index];
+            input.signature_script.clear();
+            input.script_bytes = 0;
+            input.witness = vec![signature, pubkey_bytes];
+        } else {
+            if sighash_type & 0x1f == SIGHASH_SINGLE && input_index >= self.tx_out.len() {
+                return Err(Error::msg("SIGHASH_SINGLE input has no matching output"));
+            }
+            let sighash = self.legacy_sighash(input_index, &prev_out.pk_script, sighash_type)?;
+            let mut signature = key.sign_ecdsa(sighash);
+            signature.push(sighash_byte);
+            let mut script_sig = push_data(&signature);
+            script_sig.extend_from_slice(&push_data(&pubkey_bytes));
+            let input = &mut self.tx_in[input_index];
+            input.script_bytes = script_sig.len();
+            input.signature_script = script_sig;
+            input.witness.clear();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::Address;
+    use crate::builder::TransactionBuilder;
+    use crate::script::verify_input;
+    use crate::sighash::{SIGHASH_ALL, SIGHASH_NONE};
+    use crate::{fixtures, OutPoint};
+
+    #[test]
+    fn test_wif() {
+        let key =
+            PrivateKey::from_wif("cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy").unwrap();
+        assert_eq!(key.network, Network::Testnet);
+        assert!(key.compressed);
+        assert_eq!(
+            key.to_wif(),
+            "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy"
+        );
+        assert_eq!(
+            Address::p2pkh(&key.public_key().to_bytes(), key.network).to_string(),
+            "mqwpxxvfv3QbM8PU8uBx2jaNt9btQqvQNx"
+        );
+
+        let key =
+            PrivateKey::from_wif("5JYkZjmN7PVMjJUfJWfRFwtuXTGB439XV6faajeHPAM9Z2PT2R3").unwrap();
+        assert_eq!(key.network, Network::Bitcoin);
+        assert!(!key.compressed);
+        assert_eq!(
+            key.to_wif(),
+            "5JYkZjmN7PVMjJUfJWfRFwtuXTGB439XV6faajeHPAM9Z2PT2R3"
+        );
+        let mut pubkey = key.public_key();
+        assert_eq!(
+            hex::encode(pubkey.to_bytes()),
+            "042e58afe51f9ed8ad3cc7897f634d881fdbe49a81564629ded8156bebd2ffd1af191923a2964c177f5b5923ae500fca49e99492d534aa3759d6b25a8bc971b133"
+        );
+        assert_eq!(
+            Address::p2pkh(&pubkey.to_bytes(), key.network).to_string(),
+            "1GhQvF6dL8xa6wBxLnWmHcQsurx9RxiMc8"
+        );
+        pubkey.compressed = true;
+        assert_eq!(
+            pubkey,
+            PublicKey::from_slice(
+                &hex::decode("032e58afe51f9ed8ad3cc7897f634d881fdbe49a81564629ded8156bebd2ffd1af")
+                    .unwrap()
+            )
+            .unwrap()
+        );
+
+        assert_eq!(
+            PrivateKey::from_wif(&base58::encode_check(&[0x80; 32])),
+            Err(KeyError::InvalidWifLength(32))
+        );
+        assert_eq!(
+            PrivateKey::from_wif(&base58::encode_check(&[0x81; 33])),
+            Err(KeyError::UnknownWifVersion(0x81))
+        );
+        assert_eq!(
+            PrivateKey::new(&[0; 32], Network::Bitcoin, true),
+            Err(KeyError::InvalidSecretKey)
+        );
+    }
+
+    /*서명은 low-S DER이고 같은 다이제스트에는 항상 같은 서명 */
+    #[test]
+    fn test_sign_ecdsa() {
+        let key = PrivateKey::new(&[0x11; 32], Network::Bitcoin, true).unwrap();
+        for byte in 0..32u8 {
+            let digest = [byte; 32];
+            let der = key.sign_ecdsa(digest);
+            assert_eq!(der, key.sign_ecdsa(digest));
+            assert!(key.public_key().verify_ecdsa(digest, &der));
+            assert!(!key.public_key().verify_ecdsa([byte ^ 1; 32], &der));
+
+            let mut signature = ecdsa::Signature::from_der(&der).unwrap();
+            signature.normalize_s();
+            assert_eq!(signature.serialize_der().to_vec(), der);
+        }
+    }
+
+    /*BIP143 P2WPKH 예제를 다시 서명하면 같은 witness가 나온다 */
+    #[test]
+    fn test_sign_input_matches_bip143() {
+        let fixture = &fixtures::SPENDS[2];
+        let signed = Transaction::from_hex(fixture.tx).unwrap();
+        let mut tx = signed.clone();
+        tx.tx_in[1].witness.clear();
+
+        let secret =
+            hex::decode("619c335025c7f4012e556c2a58b2506e30b8511b53ade95ea316fd8c3286feb9")
+                .unwrap();
+        let key = PrivateKey::new(&secret.try_into().unwrap(), Network::Bitcoin, true).unwrap();
+        let prev_script = hex::decode(fixture.prev_script).unwrap();
+        let prev_out = TxOut {
+            value: fixture.value,
+            pk_script_bytes: prev_script.len(),
+            pk_script: prev_script,
+        };
+        tx.sign_input(1, &prev_out, &key, SIGHASH_ALL).unwrap();
+        assert_eq!(tx.tx_in[1].witness, signed.tx_in[1].witness);
+    }
+
+    /*직접 만든 트랜잭션의 P2PKH, P2WPKH 입력을 서명하고 검증 */
+    #[test]
+    fn test_sign_and_verify() {
+        let key = PrivateKey::new(&[0x22; 32], Network::Testnet, true).unwrap();
+        let uncompressed = PrivateKey {
+            compressed: false,
+            ..key
+        };
+        let p2pkh = |key: &PrivateKey| standard::p2pkh(&key.public_key().pubkey_hash());
+        let prev_outs: Vec<TxOut> = [
+            p2pkh(&key),
+            p2pkh(&uncompressed),
+            standard::p2wpkh(&key.public_key().pubkey_hash()),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(index, pk_script)| TxOut {
+            value: 10_000 * (index as u64 + 1),
+            pk_script_bytes: pk_script.len(),
+            pk_script,
+        })
+        .collect();
+
+        let mut builder = TransactionBuilder::new();
+        for index in 0..prev_outs.len() {
+            builder = builder.add_input(OutPoint {
+                txid: [index as u8; 32],
+                vout: 0,
+            });
+        }
+        let mut tx = builder.add_output(p2pkh(&key), 50_000).build().unwrap();
+
+        tx.sign_input(0, &prev_outs[0], &key, SIGHASH_ALL).unwrap();
+        tx.sign_input(1, &prev_outs[1], &uncompressed, SIGHASH_NONE)
+            .unwrap();
+        tx.sign_input(2, &prev_outs[2], &key, SIGHASH_ALL).unwrap();
+        for (index, prev_out) in prev_outs.iter().enumerate() {
+            assert_eq!(verify_input(&tx, index, prev_out), Ok(()));
+        }
+        assert!(tx.is_segwit());
+
+        // 다른 키, 압축하지 않은 키의 P2WPKH, 지원하지 않는 output
+        assert!(tx
+            .sign_input(0, &prev_outs[0], &uncompressed, SIGHASH_ALL)
+            .is_err());
+        let p2wpkh_uncompressed = TxOut {
+            pk_script: standard::p2wpkh(&uncompressed.public_key().pubkey_hash()),
+            ..prev_outs[2].clone()
+        };
+        assert!(tx
+            .sign_input(2, &p2wpkh_uncompressed, &uncompressed, SIGHASH_ALL)
+            .is_err());
+        let p2pk = TxOut {
+            pk_script: standard::p2pk(&key.public_key().to_bytes()).unwrap(),
+            ..prev_outs[0].clone()
+        };
+        assert!(tx.sign_input(0, &p2pk, &key, SIGHASH_ALL).is_err());
+
+        // 1byte로 나타낼 수 없는 sighash 타입, 짝이 되는 출력이 없는 SIGHASH_SINGLE
+        assert!(tx
+            .sign_input(0, &prev_outs[0], &key, 0x100 | SIGHASH_ALL)
+            .is_err());
+        assert!(tx
+            .sign_input(1, &prev_outs[1], &uncompressed, SIGHASH_SINGLE)
+            .is_err());
+        assert!(tx
+            .sign_input(0, &prev_outs[0], &key, SIGHASH_SINGLE)
+            .is_ok());
+    }
+}
@@ -1 +1,3 @@
+// 라이브러리 타깃은 오류 타입을 컴파일만 하고 쓰는 곳은 바이너리(main.rs)에 있다.
+#[allow(dead_code)]
 mod error;
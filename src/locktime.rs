@@ -0,0 +1,315 @@
+use std::fmt;
+
+use anyhow::{Error, Result};
+
+use crate::{Transaction, TxIn, SEQUENCE_FINAL};
+
+/*
+lock time
+절대 lock time (nLockTime, BIP65 OP_CHECKLOCKTIMEVERIFY)
+  500000000보다 작으면 블록 높이, 크거나 같으면 유닉스 시간
+  높이는 그 높이의 블록부터, 시간은 median time past(이전 11개 블록 시간의 중앙값)가 지나면 블록에 넣을 수 있다.
+  lock time이 0이거나 모든 입력의 sequence가 SEQUENCE_FINAL이면 lock time을 보지 않는다.
+
+상대 lock time (BIP68 sequence, BIP112 OP_CHECKSEQUENCEVERIFY)
+  트랜잭션 version이 2 이상일 때 입력의 sequence로 쓰는 output이 만들어진 뒤 지나야 하는 기간을 정한다.
+  bit 31: 1이면 상대 lock time 없음
+  bit 22: 0이면 블록 수, 1이면 512초 단위 시간
+  bit 0~15: 값
+*/
+pub const LOCKTIME_THRESHOLD: u32 = 500_000_000;
+
+pub const SEQUENCE_LOCKTIME_DISABLE_FLAG: u32 = 1 << 31;
+pub const SEQUENCE_LOCKTIME_TYPE_FLAG: u32 = 1 << 22;
+pub const SEQUENCE_LOCKTIME_MASK: u32 = 0x0000ffff;
+/// 시간 단위 값은 2^9 = 512초 단위
+pub const SEQUENCE_LOCKTIME_GRANULARITY: u32 = 9;
+
+/// 절대 lock time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockTime {
+    Blocks(u32),
+    Seconds(u32),
+}
+
+impl LockTime {
+    pub fn from_consensus(lock_time: u32) -> LockTime {
+        if lock_time < LOCKTIME_THRESHOLD {
+            LockTime::Blocks(lock_time)
+        } else {
+            LockTime::Seconds(lock_time)
+        }
+    }
+
+    pub fn to_consensus(self) -> u32 {
+        match self {
+            LockTime::Blocks(height) => height,
+            LockTime::Seconds(time) => time,
+        }
+    }
+
+    /// 높이와 시간은 서로 비교할 수 없다.
+    pub fn is_same_unit(self, other: LockTime) -> bool {
+        matches!(
+            (self, other),
+            (LockTime::Blocks(_), LockTime::Blocks(_))
+                | (LockTime::Seconds(_), LockTime::Seconds(_))
+        )
+    }
+
+    /// height 높이의 블록, median time past가 time인 블록에 넣을 수 있는지
+    pub fn is_satisfied_by(self, height: u32, time: u32) -> bool {
+        match self {
+            LockTime::Blocks(lock_height) => lock_height < height,
+            LockTime::Seconds(lock_time) => lock_time < time,
+        }
+    }
+
+    /*
+    이 lock time을 만족하면 other도 만족하는지 (OP_CHECKLOCKTIMEVERIFY)
+    스크립트의 other보다 트랜잭션의 lock time이 같거나 커야 한다.
+    */
+    pub fn is_implied_by(self, other: LockTime) -> bool {
+        self.is_same_unit(other) && self.to_consensus() <= other.to_consensus()
+    }
+}
+
+impl fmt::Display for LockTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LockTime::Blocks(height) => write!(f, "block height {}", height),
+            LockTime::Seconds(time) => write!(f, "unix time {}", time),
+        }
+    }
+}
+
+/// BIP68 상대 lock time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeLockTime {
+    Blocks(u16),
+    /// 512초 단위
+    Time(u16),
+}
+
+impl RelativeLockTime {
+    /// 비활성화 비트가 켜져 있으면 None, 값이 아닌 비트(16~21, 23~30)는 무시한다.
+    pub fn from_sequence(sequence: u32) -> Option<RelativeLockTime> {
+        if sequence & SEQUENCE_LOCKTIME_DISABLE_FLAG != 0 {
+            return None;
+        }
+        let value = (sequence & SEQUENCE_LOCKTIME_MASK) as u16;
+        Some(if sequence & SEQUENCE_LOCKTIME_TYPE_FLAG != 0 {
+            RelativeLockTime::Time(value)
+        } else {
+            RelativeLockTime::Blocks(value)
+        })
+    }
+
+    pub fn to_sequence(self) -> u32 {
+        match self {
+            RelativeLockTime::Blocks(blocks) => blocks as u32,
+            RelativeLockTime::Time(units) => SEQUENCE_LOCKTIME_TYPE_FLAG | units as u32,
+        }
+    }
+
+    /// 적어도 seconds초가 지나야 하는 lock time (512초 단위로 올림)
+    pub fn from_seconds_ceil(seconds: u32) -> Result<RelativeLockTime> {
+        let units = seconds.div_ceil(1 << SEQUENCE_LOCKTIME_GRANULARITY);
+        let units =
+            u16::try_from(units).map_err(|_| Error::msg("Relative lock time is too long"))?;
+        Ok(RelativeLockTime::Time(units))
+    }
+
+    /// output이 만들어진 뒤 blocks개 블록, seconds초가 지났을 때 쓸 수 있는지
+    pub fn is_satisfied_by(self, blocks: u32, seconds: u32) -> bool {
+        match self {
+            RelativeLockTime::Blocks(lock_blocks) => lock_blocks as u32 <= blocks,
+            RelativeLockTime::Time(units) => {
+                (units as u32) << SEQUENCE_LOCKTIME_GRANULARITY <= seconds
+            }
+        }
+    }
+
+    /// 이 lock time을 만족하면 other도 만족하는지 (OP_CHECKSEQUENCEVERIFY)
+    pub fn is_implied_by(self, other: RelativeLockTime) -> bool {
+        match (self, other) {
+            (RelativeLockTime::Blocks(a), RelativeLockTime::Blocks(b)) => a <= b,
+            (RelativeLockTime::Time(a), RelativeLockTime::Time(b)) => a <= b,
+            _ => false,
+        }
+    }
+}
+
+/*
+BIP68 sequence lock
+입력들이 요구하는 마지막 높이와 median time past, -1이면 제한 없음
+블록 높이가 min_height보다 크고 이전 블록의 median time past가 min_time보다 커야 한다.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceLocks {
+    pub min_height: i64,
+    pub min_time: i64,
+}
+
+impl SequenceLocks {
+    /// height: 트랜잭션을 넣을 블록 높이, mtp: 그 이전 블록의 median time past
+    pub fn is_satisfied_by(&self, height: u32, mtp: u32) -> bool {
+        self.min_height < height as i64 && self.min_time < mtp as i64
+    }
+}
+
+impl TxIn {
+    /// 트랜잭션 version이 2 이상일 때만 적용된다.
+    pub fn relative_lock_time(&self) -> Option<RelativeLockTime> {
+        RelativeLockTime::from_sequence(self.sequence)
+    }
+}
+
+impl Transaction {
+    pub fn absolute_lock_time(&self) -> LockTime {
+        LockTime::from_consensus(self.lock_time)
+    }
+
+    /*
+    절대 lock time으로 height 높이의 블록에 넣을 수 있는지 (Bitcoin Core IsFinalTx)
+    mtp: 이전 블록의 median time past (BIP113)
+    */
+    pub fn is_final(&self, height: u32, mtp: u32) -> bool {
+        if self.lock_time == 0 || self.absolute_lock_time().is_satisfied_by(height, mtp) {
+            return true;
+        }
+        self.tx_in
+            .iter()
+            .all(|input| input.sequence == SEQUENCE_FINAL)
+    }
+
+    /*
+    BIP68 sequence lock 계산 (Bitcoin Core CalculateSequenceLocks)
+    prev_heights: 입력마다 쓰는 output이 들어 있는 블록 높이
+    prev_mtps: 입력마다 그 블록 바로 이전 블록의 median time past
+    */
+    pub fn sequence_locks(&self, prev_heights: &[u32], prev_mtps: &[u32]) -> Result<SequenceLocks> {
+        if prev_heights.len() != self.tx_in.len() || prev_mtps.len() != self.tx_in.len() {
+            return Err(Error::msg("Need the height and time of every spent output"));
+        }
+        let mut locks = SequenceLocks {
+            min_height: -1,
+            min_time: -1,
+        };
+        // version 1 트랜잭션에는 BIP68을 적용하지 않는다.
+        if (self.version as u32) < 2 {
+            return Ok(locks);
+        }
+        for (index, input) in self.tx_in.iter().enumerate() {
+            match input.relative_lock_time() {
+                None => {}
+                Some(RelativeLockTime::Blocks(blocks)) => {
+                    let height = prev_heights[index] as i64 + blocks as i64 - 1;
+                    locks.min_height = locks.min_height.max(height);
+                }
+                Some(RelativeLockTime::Time(units)) => {
+                    let seconds = (units as i64) << SEQUENCE_LOCKTIME_GRANULARITY;
+                    locks.min_time = locks.min_time.max(prev_mtps[index] as i64 + seconds - 1);
+                }
+            }
+        }
+        Ok(locks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::TransactionBuilder;
+    use crate::OutPoint;
+
+    fn tx(version: i32, lock_time: u32, sequences: &[u32]) -> Transaction {
+        let mut builder = TransactionBuilder::new()
+            .version(version)
+            .lock_time(lock_time);
+        for (vout, &sequence) in sequences.iter().enumerate() {
+            let outpoint = OutPoint {
+                txid: [1; 32],
+                vout: vout as u32,
+            };
+            builder = builder.add_input_with_sequence(outpoint, sequence);
+        }
+        builder.add_output(vec![], 0).build().unwrap()
+    }
+
+    #[test]
+    fn test_lock_time() {
+        assert_eq!(
+            LockTime::from_consensus(499_999_999),
+            LockTime::Blocks(499_999_999)
+        );
+        assert_eq!(
+            LockTime::from_consensus(500_000_000),
+            LockTime::Seconds(500_000_000)
+        );
+        assert!(LockTime::Blocks(100).is_implied_by(LockTime::Blocks(100)));
+        assert!(!LockTime::Blocks(101).is_implied_by(LockTime::Blocks(100)));
+        assert!(!LockTime::Blocks(100).is_implied_by(LockTime::Seconds(600_000_000)));
+
+        // 높이 100 lock time은 101번째 블록부터
+        let locked = tx(1, 100, &[0xfffffffe]);
+        assert!(!locked.is_final(100, 0));
+        assert!(locked.is_final(101, 0));
+        assert!(tx(1, 100, &[SEQUENCE_FINAL]).is_final(100, 0));
+        assert!(tx(1, 0, &[0]).is_final(0, 0));
+        let locked = tx(1, 600_000_000, &[0]);
+        assert!(!locked.is_final(u32::MAX, 600_000_000));
+        assert!(locked.is_final(0, 600_000_001));
+    }
+
+    #[test]
+    fn test_sequence_locks() {
+        assert_eq!(RelativeLockTime::from_sequence(SEQUENCE_FINAL), None);
+        assert_eq!(
+            RelativeLockTime::from_sequence(0x003f_0010),
+            Some(RelativeLockTime::Blocks(16))
+        );
+        assert_eq!(
+            RelativeLockTime::from_sequence(0x0040_0002),
+            Some(RelativeLockTime::Time(2))
+        );
+        assert_eq!(
+            RelativeLockTime::from_seconds_ceil(513).unwrap(),
+            RelativeLockTime::Time(2)
+        );
+        assert!(RelativeLockTime::from_seconds_ceil(512 * 65536).is_err());
+        assert_eq!(RelativeLockTime::Time(2).to_sequence(), 0x0040_0002);
+        assert!(RelativeLockTime::Time(2).is_satisfied_by(0, 1024));
+        assert!(!RelativeLockTime::Blocks(2).is_satisfied_by(1, 1024));
+
+        // 높이 1000 블록의 output을 10블록 뒤에, 높이 2000 블록의 output을 1024초 뒤에 쓴다.
+        let spend = tx(
+            2,
+            0,
+            &[
+                RelativeLockTime::Blocks(10).to_sequence(),
+                RelativeLockTime::Time(2).to_sequence(),
+                SEQUENCE_FINAL,
+            ],
+        );
+        let locks = spend
+            .sequence_locks(&[1000, 2000, 3000], &[0, 1_600_000_000, 0])
+            .unwrap();
+        assert_eq!(
+            locks,
+            SequenceLocks {
+                min_height: 1009,
+                min_time: 1_600_001_023,
+            }
+        );
+        assert!(!locks.is_satisfied_by(1009, 1_600_001_024));
+        assert!(!locks.is_satisfied_by(3001, 1_600_001_023));
+        assert!(locks.is_satisfied_by(3001, 1_600_001_024));
+
+        // version 1에는 적용하지 않는다.
+        let spend = tx(1, 0, &[RelativeLockTime::Blocks(10).to_sequence()]);
+        let locks = spend.sequence_locks(&[1000], &[0]).unwrap();
+        assert!(locks.is_satisfied_by(0, 0));
+        assert!(spend.sequence_locks(&[], &[]).is_err());
+    }
+}
@@ -4,16 +4,39 @@ use crate::error::BlockchainError;
 use anyhow::{Error, Result};
 use serde::{Deserialize, Serialize};
 mod error;
-use crypto::{digest::Digest, sha3::Sha3};
+use crypto::{digest::Digest, ripemd160::Ripemd160, sha2::Sha256, sha3::Sha3};
+pub mod accumulator;
+pub mod address;
+pub mod base58;
+pub mod base64;
+pub mod bech32;
+pub mod bip32;
+pub mod bip39;
+pub mod builder;
+pub mod encode;
+pub mod fee;
+#[cfg(test)]
+mod fixtures;
+pub mod keys;
+pub mod locktime;
+pub mod opcodes;
+pub mod psbt;
+pub mod script;
+pub mod sighash;
+pub mod snapshot;
+pub mod standard;
+pub mod tamper;
+pub mod taproot;
+pub mod validation;
 pub type Data = Vec<u8>;
 pub type Hash = Vec<u8>;
 /*블록헤더 */
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BlockHeaders {
     version: i32,
-    previous_block_header_hash: String,
-    merkle_root_hash: String,
-    time: u8,
+    previous_block_header_hash: [u8; 32],
+    merkle_root_hash: [u8; 32],
+    time: u32,
     nbits: u32,
     nonce: u32,
 }
@@ -24,18 +47,51 @@ pub struct Block {
     pub txns: Vec<Transaction>,
 }
 /*
+OutPoint
+txid: 사용할 output이 포함된 트랜잭션의 txid (내부 바이트 순서)
+vout: 그 트랜잭션 안에서 output의 순번
+*/
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OutPoint {
+    pub txid: [u8; 32],
+    pub vout: u32,
+}
+impl OutPoint {
+    /// 코인베이스 입력이 가리키는 빈 outpoint
+    pub fn null() -> OutPoint {
+        OutPoint {
+            txid: [0; 32],
+            vout: 0xffffffff,
+        }
+    }
+}
+/// witness commitment output의 앞부분 (OP_RETURN, 36bytes push, 0xaa21a9ed)
+pub const WITNESS_COMMITMENT_HEADER: [u8; 6] = [0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+/// 발행될 수 있는 최대 금액 (2100만 BTC, 사토시)
+pub const MAX_MONEY: u64 = 21_000_000 * 100_000_000;
+/// witness가 아닌 바이트 하나는 4 weight unit, witness 바이트는 1 weight unit (BIP141)
+pub const WITNESS_SCALE_FACTOR: usize = 4;
+/// 블록 weight 상한 (SegWit 이전의 1MB 블록 크기 제한을 대신한다)
+pub const MAX_BLOCK_WEIGHT: usize = 4_000_000;
+/// 시퀀스 번호 기본값
+pub const SEQUENCE_FINAL: u32 = 0xffffffff;
+/// 블록 보상이 반으로 줄어드는 블록 간격
+pub const SUBSIDY_HALVING_INTERVAL: u32 = 210_000;
+/*
 TxIn
 previous_output: 사용중인 이전 아웃 포인트
-script bytes:서명 스크립트의 바이트 수, u8
+script bytes:서명 스크립트의 바이트 수
 signature script :outpoint의 pubkey 스크립트에 있는 조건을 만족시키는 스크립트 언어 스크립트. 데이터 푸시만 포함
 sequence:시퀀스 번호. Bitcoin Core 및 거의 모든 다른 프로그램의 기본값은 0xffffffff ,uint32
+witness: SegWit 입력의 witness 스택 (서명 데이터), 레거시 입력은 비어 있음
 */
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TxIn {
-    previous_output: String,
-    script_bytes: String,
-    signature_script: String,
-    sequence: String,
+    previous_output: OutPoint,
+    script_bytes: usize,
+    signature_script: Vec<u8>,
+    sequence: u32,
+    witness: Vec<Vec<u8>>,
 }
 /*
 TxOut
@@ -45,9 +101,9 @@ pk_script 송금자의 정보가 담긴 데이터
 */
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct TxOut {
-    value: String,
-    pk_script_bytes: String,
-    pk_script: String,
+    value: u64,
+    pk_script_bytes: usize,
+    pk_script: Vec<u8>,
 }
 /*
 트랜잭션
@@ -73,7 +129,7 @@ pub struct Transaction {
     tx_in: Vec<TxIn>,
     tx_out_count: usize,
     tx_out: Vec<TxOut>,
-    lock_time: String,
+    lock_time: u32,
 }
 /*
 머클트리
@@ -89,22 +145,46 @@ MerkleRoot:최종 결과 해시값
 맨위에 해시를 루트
 홀수일 경우 복사해서 해시
 */
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MerkleRoot {
     pub hash: String,
 }
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct MerkleTree {
     pub nodes: Vec<Hash>,
     pub levels: usize,
     pub merkle_root: MerkleRoot,
+    pub mode: MerkleMode,
+    /// 리프 수, Bitcoin 방식에서는 2의 거듭제곱이 아닐 수 있다.
+    pub leaf_count: usize,
+}
+/*
+머클트리 해시 방식
+Legacy: 리프를 직렬화하고 이어붙여서 직렬화, 리프 수는 2의 거듭제곱
+Bitcoin: 비트코인 블록 헤더의 머클루트와 같은 방식
+ - 리프는 txid(내부 바이트 순서, 32bytes) 그대로
+ - 두 해시를 이어붙여 double-SHA256
+ - 레벨의 노드 수가 홀수면 마지막 해시를 복사해서 짝을 맞춤
+ - 루트는 뒤집어서(표시 순서) hex로 표시
+Taproot: Taproot 스크립트 트리(MAST) 방식
+ - 리프는 TapLeaf 해시(32bytes) 그대로
+ - 두 해시를 사전순으로 정렬해서 이어붙인 뒤 TapBranch 태그 해시
+ - 레벨의 노드 수가 홀수면 마지막 노드는 짝 없이 그대로 위로 올린다
+ - 루트는 내부 바이트 순서 그대로 hex로 표시
+*/
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum MerkleMode {
+    #[default]
+    Legacy,
+    Bitcoin,
+    Taproot,
 }
 /*
 해시 디렉션
 증명 해시를 연결할 때 해시를 넣을 쪽에
 */
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 pub enum HashDirection {
     Left,
     Right,
@@ -112,11 +192,17 @@ pub enum HashDirection {
 /*
 Proof증명
 */
-#[derive(Debug, Default)]
-pub struct Proof<'a> {
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Proof {
+    /// 증명 대상 리프의 인덱스
+    leaf_index: usize,
+    /// 증명을 만든 트리의 전체 리프 수
+    num_leaves: usize,
+    /// 증명을 만든 트리의 해시 방식
+    mode: MerkleMode,
     /// 증명을 확인할 때 사용할 해시
     /// 튜플의 첫 번째 요소는 연결할 때 해시가 있어야 하는 쪽
-    hashes: Vec<(HashDirection, &'a Hash)>,
+    hashes: Vec<(HashDirection, Hash)>,
 }
 
 /*
@@ -137,105 +223,271 @@ pub struct Proof<'a> {
 
 
 */
-//트랜잭션
-impl TxOut {
-    pub fn set_output() {
-        println!("{}", "아웃풋 생성");
+//트랜잭션 (생성은 builder::TransactionBuilder)
+impl Transaction {
+    /*
+    txid
+    witness를 뺀 합의 직렬화의 double-SHA256 (내부 바이트 순서)
+    표시할 때는 to_display_hex로 뒤집어서 보여준다.
+    */
+    pub fn txid(&self) -> [u8; 32] {
+        sha256d(&encode::serialize_without_witness(self))
+            .try_into()
+            .unwrap()
     }
-}
-impl TxIn {
-    pub fn set_input() {
-        println!("{}", "인풋생성");
+
+    /*
+    wtxid
+    witness 데이터까지 포함한 직렬화(BIP144)의 double-SHA256
+    witness가 없는 트랜잭션은 txid와 같다.
+    */
+    pub fn wtxid(&self) -> [u8; 32] {
+        sha256d(&encode::serialize(self)).try_into().unwrap()
+    }
+
+    /// 이전 output이 없는(txid 0, index 0xffffffff) 입력 하나만 있으면 코인베이스
+    pub fn is_coinbase(&self) -> bool {
+        matches!(self.tx_in.as_slice(), [input] if input.previous_output == OutPoint::null())
+    }
+
+    /// witness가 있는 입력이 하나라도 있으면 SegWit 트랜잭션
+    pub fn is_segwit(&self) -> bool {
+        self.tx_in.iter().any(|input| !input.witness.is_empty())
+    }
+
+    /// output 금액의 합(사토시), 더하다가 넘치면 None
+    pub fn output_value(&self) -> Option<u64> {
+        self.tx_out
+            .iter()
+            .try_fold(0u64, |total, output| total.checked_add(output.value))
+    }
+
+    /// witness를 뺀 직렬화 크기 (bytes)
+    pub fn base_size(&self) -> usize {
+        encode::serialize_without_witness(self).len()
+    }
+
+    /// marker, flag, witness까지 포함한 직렬화 크기 (bytes)
+    pub fn total_size(&self) -> usize {
+        encode::serialize(self).len()
+    }
+
+    /*
+    weight (BIP141)
+    base_size * 3 + total_size
+    witness가 없는 트랜잭션은 크기의 4배
+    */
+    pub fn weight(&self) -> usize {
+        self.base_size() * (WITNESS_SCALE_FACTOR - 1) + self.total_size()
+    }
+
+    /// 가상 크기 (vbytes), weight / 4를 올림. 수수료율은 이 크기로 계산한다.
+    pub fn vsize(&self) -> usize {
+        self.weight().div_ceil(WITNESS_SCALE_FACTOR)
     }
 }
-impl Transaction {
-    pub fn set_transaction(data: &Vec<TxIn>) -> Transaction {
-        let test = vec![TxOut {
-            value: "amount".to_string(),
-            pk_script_bytes: "pk_script_bytes".to_string(),
-            pk_script: "pk_script".to_string(),
-        }];
-
-        Transaction {
-            version: 1,
-            tx_in_count: get_input_len(data.clone()),
-            tx_in: data.clone(),
-            tx_out_count: 1,
-            tx_out: test,
-            lock_time: "10분제한".to_string(),
+impl Block {
+    /// 헤더, 트랜잭션 수, witness를 뺀 트랜잭션들의 크기 (bytes)
+    pub fn base_size(&self) -> usize {
+        self.header_and_count_size() + self.txns.iter().map(Transaction::base_size).sum::<usize>()
+    }
+
+    /// witness까지 포함한 직렬화 크기 (bytes)
+    pub fn total_size(&self) -> usize {
+        self.header_and_count_size() + self.txns.iter().map(Transaction::total_size).sum::<usize>()
+    }
+
+    fn header_and_count_size(&self) -> usize {
+        80 + encode::serialize(&encode::CompactSize(self.txns.len() as u64)).len()
+    }
+
+    /// base_size * 3 + total_size
+    pub fn weight(&self) -> usize {
+        self.base_size() * (WITNESS_SCALE_FACTOR - 1) + self.total_size()
+    }
+
+    /// 블록 weight가 MAX_BLOCK_WEIGHT(4M weight unit)를 넘지 않는지
+    pub fn check_weight(&self) -> bool {
+        self.weight() <= MAX_BLOCK_WEIGHT
+    }
+
+    /// 블록 안 트랜잭션들의 txid로 Bitcoin 방식 머클 트리를 구성
+    pub fn merkle_tree(&self) -> MerkleTree {
+        let txids: Vec<Data> = self.txns.iter().map(|tx| tx.txid().to_vec()).collect();
+        MerkleTree::with_mode(&txids, MerkleMode::Bitcoin)
+    }
+
+    /// 다시 계산한 머클루트가 헤더의 머클루트와 같은지 확인
+    pub fn check_merkle_root(&self) -> bool {
+        !self.txns.is_empty()
+            && self.merkle_tree().root_hash() == self.block_header.merkle_root_hash
+    }
+
+    /*
+    witness 머클 트리 (BIP141)
+    트랜잭션들의 wtxid로 만든 트리, 코인베이스의 wtxid는 0으로 채운다.
+    */
+    pub fn witness_merkle_tree(&self) -> MerkleTree {
+        witness_merkle_tree(self.txns.get(1..).unwrap_or_default())
+    }
+
+    /*
+    witness commitment
+    double-SHA256(witness 루트 || witness reserved value)
+    reserved value는 코인베이스 입력 witness의 첫 항목(32bytes)
+    */
+    pub fn witness_commitment(&self) -> Option<[u8; 32]> {
+        let (coinbase, txns) = self.txns.split_first()?;
+        let reserved = match coinbase.tx_in.first()?.witness.as_slice() {
+            [reserved] if reserved.len() == 32 => reserved,
+            _ => return None,
+        };
+        Some(witness_commitment(txns, reserved))
+    }
+
+    /*
+    코인베이스의 witness commitment output 확인
+    OP_RETURN 0x24 0xaa21a9ed 로 시작하는 output 중 마지막 것을 쓴다.
+    witness 트랜잭션이 없는 블록은 commitment가 없어도 된다.
+    */
+    pub fn check_witness_commitment(&self) -> bool {
+        let committed = self.txns.first().and_then(|coinbase| {
+            coinbase
+                .tx_out
+                .iter()
+                .rev()
+                .find(|output| {
+                    output.pk_script.len() >= 38
+                        && output.pk_script[..6] == WITNESS_COMMITMENT_HEADER
+                })
+                .map(|output| &output.pk_script[6..38])
+        });
+        match committed {
+            Some(committed) => {
+                self.witness_commitment().as_ref().map(|c| &c[..]) == Some(committed)
+            }
+            None => !self.txns.iter().any(Transaction::is_segwit),
         }
     }
 }
+
+/*
+코인베이스 뒤 트랜잭션들(txns)로 만드는 witness 머클 트리와 commitment
+코인베이스의 wtxid는 0이라서 코인베이스를 만들기 전에도 계산할 수 있다. (CoinbaseBuilder)
+*/
+fn witness_merkle_tree(txns: &[Transaction]) -> MerkleTree {
+    let wtxids: Vec<Data> = std::iter::once(vec![0; 32])
+        .chain(txns.iter().map(|tx| tx.wtxid().to_vec()))
+        .collect();
+    MerkleTree::with_mode(&wtxids, MerkleMode::Bitcoin)
+}
+
+fn witness_commitment(txns: &[Transaction], reserved: &[u8]) -> [u8; 32] {
+    let mut data = witness_merkle_tree(txns).root_hash();
+    data.extend_from_slice(reserved);
+    sha256d(&data)
+        .try_into()
+        .expect("double-SHA256 is 32 bytes")
+}
+impl BlockHeaders {
+    /// 80bytes 블록 헤더 파싱 (해시는 내부 바이트 순서)
+    pub fn from_bytes(raw: &[u8; 80]) -> BlockHeaders {
+        encode::deserialize(raw).expect("80 bytes is always a complete header")
+    }
+}
 impl MerkleTree {
     //트랜잭션들을 받아서 모임일 받아서
     pub fn mekle_tree_return(datas: Vec<Vec<u8>>) -> MerkleRoot {
         println!("트랜잭션들{:?}", &datas);
-        //확인하고
-        assert!(is_power_of_two(datas.len()));
-        println!("이거{}", is_power_of_two(datas.len()));
-        let num_levels = (datas.len() as f64).log2() as usize;
+        let mekle_tree = MerkleTree::new(&datas);
+        println!("{}", mekle_tree.merkle_root.hash);
+
+        mekle_tree.merkle_root
+    }
+
+    /// 데이터 모음으로 머클 트리를 구성
+    pub fn new(datas: &[Data]) -> MerkleTree {
+        MerkleTree::with_mode(datas, MerkleMode::Legacy)
+    }
+
+    /// 주어진 해시 방식으로 머클 트리를 구성 (Bitcoin 방식의 데이터는 txid)
+    pub fn with_mode(datas: &[Data], mode: MerkleMode) -> MerkleTree {
         //트랜잭션들을 받아서 직렬화
-        let mut hashes: Vec<Vec<Hash>> = vec![datas.iter().map(hash_data).collect()];
+        MerkleTree::from_leaf_hashes(
+            datas.iter().map(|data| leaf_hash(mode, data)).collect(),
+            mode,
+        )
+    }
+
+    /// 이미 계산된 리프 해시로 머클 트리를 구성
+    fn from_leaf_hashes(leaves: Vec<Hash>, mode: MerkleMode) -> MerkleTree {
+        //확인하고
+        match mode {
+            MerkleMode::Legacy => assert!(is_power_of_two(leaves.len())),
+            MerkleMode::Bitcoin => assert!(!leaves.is_empty(), "Bitcoin merkle tree needs a txid"),
+            MerkleMode::Taproot => assert!(!leaves.is_empty(), "Taproot tree needs a leaf"),
+        }
+        let leaf_count = leaves.len();
+        let mut hashes: Vec<Vec<Hash>> = vec![leaves];
         let mut last_level = &hashes[0];
-        println!("몇번인지확인:{}", num_levels);
-        println!("hashe집합:{:?}", hashes);
-        println!("마지막해시 last_level:{:?}", last_level.len());
 
-        for _ in 0..num_levels {
-            let mut next_level = vec![MerkleTree::construct_level_up(last_level)];
+        while last_level.len() > 1 {
+            let mut next_level = vec![MerkleTree::construct_level_up(last_level, mode)];
             hashes.append(&mut next_level);
             last_level = &hashes[hashes.len() - 1];
         }
-        println!("last_level{:?}", last_level[0]);
         //into_iter:소유권을 가져감
         //flatten:중첩된 구조를 평면화
-        let test = hash_to_str(&last_level[0]);
-        println!("{}", test);
-        println!("{}", test);
-        let mekle_tree = MerkleTree {
-            nodes: hashes.into_iter().flatten().collect(),
-            levels: num_levels + 1,
-            merkle_root: MerkleRoot { hash: test.clone() },
+        let root = match mode {
+            MerkleMode::Legacy => hash_to_str(&last_level[0]),
+            MerkleMode::Bitcoin => to_display_hex(&last_level[0]),
+            MerkleMode::Taproot => hex::encode(&last_level[0]),
         };
-
-        MerkleRoot { hash: test.clone() }
+        MerkleTree {
+            levels: hashes.len(),
+            nodes: hashes.into_iter().flatten().collect(),
+            merkle_root: MerkleRoot { hash: root },
+            mode,
+            leaf_count,
+        }
     }
 
-    //트랙잭션 hash
+    //트랙잭션 hash (txid를 표시 순서 hex로)
     pub fn previous_output(data: &Transaction) -> String {
-        let txs_ser = serialize(data);
-        match txs_ser {
-            Ok(txs_ser) => {
-                let hashs = hash_to_str(&txs_ser);
-                hashs
-            }
-            Err(e) => {
-                println!("err");
-                assert!(false);
-                "error".to_string()
-            }
-        }
+        to_display_hex(&data.txid())
     }
     //머클트리 체인 연결
 
-    fn hash_concat(h1: &Hash, h2: &Hash) -> Hash {
+    fn hash_concat(mode: MerkleMode, h1: &Hash, h2: &Hash) -> Hash {
         //반복자 체인
         //두개의 반복자를 가져와서 둘모두에 대한  새로운 반복자 생성
         let h3 = h1.iter().chain(h2).copied().collect();
-        hash_data(&h3)
+        match mode {
+            MerkleMode::Legacy => hash_data(&h3),
+            MerkleMode::Bitcoin => sha256d(&h3),
+            // 정렬해서 이어붙이므로 순서가 상관없다
+            MerkleMode::Taproot => taproot::tap_branch_hash(h1, h2).to_vec(),
+        }
     }
     //한단계 위로
     /*
     해시집합 받아서
     하위해시를 연결
+    Bitcoin 방식에서 홀수개면 마지막 해시를 자기 자신과 연결
+    Taproot 방식에서 홀수개면 마지막 해시를 그대로 올림
     */
-    fn construct_level_up(level: &[Hash]) -> Vec<Hash> {
-        assert!(is_power_of_two(level.len()));
+    fn construct_level_up(level: &[Hash], mode: MerkleMode) -> Vec<Hash> {
+        if mode == MerkleMode::Legacy {
+            assert!(is_power_of_two(level.len()));
+        }
         // 하위 해시를 연결하여 상위 레벨을 찾고 이전 레벨로 이동
         //슬라이스의 시작 부분에서 시작하여 한 번에 슬라이스의 chunk_size 요소에 대한 반복자를 반환
         level
             .chunks(2)
-            .map(|pair| Self::hash_concat(&pair[0], &pair[1]))
+            .map(|pair| match pair {
+                [single] if mode == MerkleMode::Taproot => single.clone(),
+                _ => Self::hash_concat(mode, &pair[0], pair.last().unwrap()),
+            })
             .collect()
     }
     /// 머클 트리의 루트 해시를 반환
@@ -245,7 +497,7 @@ impl MerkleTree {
 
     /// Merkle 트리를 구성하는 데 사용된 데이터의 수를 반환
     pub fn num_leaves(&self) -> usize {
-        2_usize.pow((self.levels - 1) as u32)
+        self.leaf_count
     }
 
     /// 머클 트리의 levels(기본 데이터의 해시)를 반환
@@ -253,14 +505,18 @@ impl MerkleTree {
         &self.nodes[0..self.num_leaves()]
     }
 
-    /// 주어진 노드 인덱스의 부모 노드 인덱스를 반환
-    fn parent_index(&self, index: usize) -> usize {
-        // 이 함수는 내부적으로만 사용해야 하므로 여기에서 주장하는 것이 좋다.
-
-        assert!(index != self.nodes.len() - 1, "Root node has no parent");
-        assert!(index < self.nodes.len(), "Index outside of tree");
+    /// 주어진 레벨의 노드 수를 반환 (0 레벨이 리프)
+    fn level_width(&self, level: usize) -> usize {
+        level_width(self.leaf_count, level)
+    }
 
-        self.nodes.len() - ((self.nodes.len() - index) / 2)
+    /// 주어진 레벨과 그 레벨 안의 인덱스에 있는 노드 해시를 반환
+    pub fn node(&self, level: usize, index: usize) -> Option<&Hash> {
+        if level >= self.levels || index >= self.level_width(level) {
+            return None;
+        }
+        let offset: usize = (0..level).map(|l| self.level_width(l)).sum();
+        self.nodes.get(offset + index)
     }
 
     /*
@@ -271,20 +527,41 @@ impl MerkleTree {
         if leaf_index >= self.num_leaves() {
             return Err(Error::msg("message"));
         }
-        let mut proof = Proof::default();
+        let mut proof = Proof {
+            leaf_index,
+            num_leaves: self.num_leaves(),
+            mode: self.mode,
+            ..Proof::default()
+        };
         let mut current_known_index = leaf_index;
-        for _ in 0..self.levels - 1 {
+        for level in 0..self.levels - 1 {
             // 이미 한 쪽의 해시를 알고 있거나 이미 계산할 수 있다.
             // 쌍이므로 증명을 위해 다른 하나를 반환해야 한다.
+            // 짝이 없는 마지막 노드는 자기 자신과 연결된다. (Taproot 방식은 그대로 올라간다)
+            let width = self.level_width(level);
+            if self.mode == MerkleMode::Taproot
+                && current_known_index == width - 1
+                && width % 2 == 1
+            {
+                current_known_index /= 2;
+                continue;
+            }
             let corresponding_hash = if current_known_index % 2 == 0 {
-                (HashDirection::Right, &self.nodes[current_known_index + 1])
+                let sibling = (current_known_index + 1).min(width - 1);
+                (
+                    HashDirection::Right,
+                    self.node(level, sibling).unwrap().clone(),
+                )
             } else {
-                (HashDirection::Left, &self.nodes[current_known_index - 1])
+                (
+                    HashDirection::Left,
+                    self.node(level, current_known_index - 1).unwrap().clone(),
+                )
             };
             proof.hashes.push(corresponding_hash);
             // 이제 부모의 해시를 계산할 수 있으므로 부모의
             // 이 노드는 이제 알려진 노드
-            current_known_index = self.parent_index(current_known_index);
+            current_known_index /= 2;
         }
         Ok(proof)
     }
@@ -294,7 +571,7 @@ impl MerkleTree {
     머클 트리에서 데이터를 찾을 수 없으면 오류를 반환.
     */
     pub fn get_merkle_proof_by_data(&self, data: &Data) -> Result<Proof> {
-        let data_hash = hash_data(data);
+        let data_hash = leaf_hash(self.mode, data);
 
         //position:반복기에서 요소를 검색하여 해당 index 몇번쨰인지 반환
         let leaf_index = self
@@ -307,14 +584,98 @@ impl MerkleTree {
     }
 }
 
-/// 주어진 증명이 주어진 루트 해시와 데이터에 유효한지 확인
-pub fn verify_merkle_proof(proof: &Proof, data: &Data, root_hash: &Hash) -> bool {
-    let mut current_hash = hash_data(data);
+impl Proof {
+    /// 증명 대상 리프의 인덱스를 반환
+    pub fn leaf_index(&self) -> usize {
+        self.leaf_index
+    }
+
+    /// 증명을 만든 트리의 전체 리프 수를 반환
+    pub fn num_leaves(&self) -> usize {
+        self.num_leaves
+    }
+
+    /// 증명을 만든 트리의 해시 방식을 반환
+    pub fn mode(&self) -> MerkleMode {
+        self.mode
+    }
+
+    /*
+    리프 인덱스와 전체 리프 수로 각 레벨에서 형제 해시가 붙어야 할 방향을 계산
+    짝수 인덱스는 형제가 오른쪽, 홀수 인덱스는 형제가 왼쪽
+    (Bitcoin 방식에서 짝이 없는 마지막 노드는 자기 자신이 오른쪽, Taproot 방식에서는 형제가 없다)
+    인덱스가 범위를 벗어나거나 Legacy 방식에서 리프 수가 2의 거듭제곱이 아니면 None
+    */
+    pub fn expected_directions(
+        leaf_index: usize,
+        num_leaves: usize,
+        mode: MerkleMode,
+    ) -> Option<Vec<HashDirection>> {
+        if leaf_index >= num_leaves || (mode == MerkleMode::Legacy && !is_power_of_two(num_leaves))
+        {
+            return None;
+        }
+        let mut directions = vec![];
+        let mut index = leaf_index;
+        let mut width = num_leaves;
+        while width > 1 {
+            if mode == MerkleMode::Taproot && index == width - 1 && width % 2 == 1 {
+                index /= 2;
+                width = width.div_ceil(2);
+                continue;
+            }
+            directions.push(if index.is_multiple_of(2) {
+                HashDirection::Right
+            } else {
+                HashDirection::Left
+            });
+            index /= 2;
+            width = width.div_ceil(2);
+        }
+        Some(directions)
+    }
+}
+
+/*
+주어진 증명이 주어진 루트 해시와 데이터에 유효한지 확인
+증명이 검증자가 기대하는 리프 위치(leaf_index, num_leaves)에 대한 것이어야 하고
+증명 안의 방향들이 그 위치에서 유도한 방향과 모두 일치해야 한다.
+방향만 맞춰서 다른 인덱스의 증명을 내미는 것을 막기 위함
+해시 방식도 증명을 만든 쪽이 아니라 검증자가 루트를 만든 방식(mode)으로 정한다.
+Taproot 방식은 두 해시를 정렬해서 이어 붙이므로 방향으로는 위치가 묶이지 않는다.
+*/
+pub fn verify_merkle_proof(
+    proof: &Proof,
+    data: &Data,
+    leaf_index: usize,
+    num_leaves: usize,
+    root_hash: &Hash,
+    mode: MerkleMode,
+) -> bool {
+    if proof.mode != mode || proof.leaf_index != leaf_index || proof.num_leaves != num_leaves {
+        return false;
+    }
+    let expected = match Proof::expected_directions(leaf_index, num_leaves, mode) {
+        Some(expected) => expected,
+        None => return false,
+    };
+    if expected.len() != proof.hashes.len()
+        || expected
+            .iter()
+            .zip(proof.hashes.iter())
+            .any(|(expected, (direction, _))| expected != direction)
+    {
+        return false;
+    }
+
+    let Some(mut current_hash) = try_leaf_hash(mode, data) else {
+        return false;
+    };
 
     for (hash_direction, hash) in proof.hashes.iter() {
         current_hash = match hash_direction {
-            HashDirection::Left => MerkleTree::hash_concat(hash, &current_hash),
-            HashDirection::Right => MerkleTree::hash_concat(&current_hash, hash),
+            HashDirection::Left => MerkleTree::hash_concat(mode, hash, &current_hash),
+            HashDirection::Right => MerkleTree::hash_concat(mode, &current_hash, hash),
         };
     }
 
@@ -325,6 +686,21 @@ fn hash_data(data: &Data) -> Hash {
     let serialize_transaction3 = bincode::serialize(&data).unwrap();
     serialize_transaction3
 }
+//해시 방식에 따른 리프 해시, Bitcoin 방식은 txid를, Taproot 방식은 TapLeaf 해시를 그대로 리프로 쓴다
+fn leaf_hash(mode: MerkleMode, data: &Data) -> Hash {
+    try_leaf_hash(mode, data).expect("txid and tap leaf hash must be 32 bytes")
+}
+//믿을 수 없는 데이터용, Bitcoin, Taproot 방식에서 32bytes가 아니면 None
+fn try_leaf_hash(mode: MerkleMode, data: &Data) -> Option<Hash> {
+    match mode {
+        MerkleMode::Legacy => Some(hash_data(data)),
+        MerkleMode::Bitcoin | MerkleMode::Taproot => (data.len() == 32).then(|| data.clone()),
+    }
+}
+//리프 수가 leaf_count인 트리에서 주어진 레벨의 노드 수 (홀수는 올림)
+fn level_width(leaf_count: usize, level: usize) -> usize {
+    (0..level).fold(leaf_count, |width, _| width.div_ceil(2))
+}
 
 fn is_power_of_two(n: usize) -> bool {
     //4==0 4 &4 -4-1 ==0
@@ -365,27 +741,64 @@ pub fn hash_to_str(data: &[u8]) -> String {
     //해시 다이제스트 읽기
     hasher.result_str()
 }
+//SHA256
+pub fn sha256(data: &[u8]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.input(data);
+    let mut hash = vec![0u8; 32];
+    hasher.result(&mut hash);
+    hash
+}
+/*
+블록 보상 (코인베이스가 새로 발행할 수 있는 금액, 사토시)
+50 BTC에서 시작해서 210000 블록마다 절반
+64번 줄면 0 (오른쪽 시프트가 정의되지 않으므로 따로 처리)
+*/
+pub fn block_subsidy(height: u32) -> u64 {
+    let halvings = height / SUBSIDY_HALVING_INTERVAL;
+    if halvings >= 64 {
+        return 0;
+    }
+    (50 * 100_000_000u64) >> halvings
+}
+//double-SHA256
+pub fn sha256d(data: &[u8]) -> Hash {
+    sha256(&sha256(data))
+}
+//RIPEMD160(SHA256), 공개키와 스크립트 해시(20bytes)
+pub fn hash160(data: &[u8]) -> Hash {
+    let mut hasher = Ripemd160::new();
+    hasher.input(&sha256(data));
+    let mut hash = vec![0u8; 20];
+    hasher.result(&mut hash);
+    hash
+}
+
+//비트코인 해시는 내부 바이트 순서를 뒤집어서 hex로 표시
+pub fn to_display_hex(hash: &[u8]) -> String {
+    hash.iter()
+        .rev()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
 //input의 갯수 받아오기
 pub fn get_input_len(inputs: Vec<TxIn>) -> usize {
     inputs.len()
 }
 fn main() {
-    let inputdata1 = TxIn {
-        previous_output: "previous_output".to_string(),
-        script_bytes: "script_bytes".to_string(),
-        signature_script: "signature_script".to_string(),
-        sequence: "sequence".to_string(),
-    };
-    let inputdata2 = TxIn {
-        previous_output: "previous_output".to_string(),
-        script_bytes: "script_bytes".to_string(),
-        signature_script: "signature_script".to_string(),
-        sequence: "sequence".to_string(),
-    };
-    let mut input: Vec<TxIn> = vec![];
-    input.push(inputdata1);
-    input.push(inputdata2);
-    let result_tx = Transaction::set_transaction(&input);
+    let result_tx = builder::TransactionBuilder::new()
+        .add_input(OutPoint {
+            txid: [1; 32],
+            vout: 0,
+        })
+        .add_input(OutPoint {
+            txid: [2; 32],
+            vout: 0,
+        })
+        .add_output(vec![], 0)
+        .build()
+        .expect("valid transaction");
 
     println!("{:?}", result_tx);
 }
@@ -400,37 +813,39 @@ mod tests {
             version: 1,
             tx_in_count: 1,
             tx_in: vec![TxIn {
-                previous_output: "s".to_string(),
-                script_bytes: "s".to_string(),
-                signature_script: "s".to_string(),
-                sequence: "s".to_string(),
+                previous_output: OutPoint::default(),
+                script_bytes: 1,
+                signature_script: b"s".to_vec(),
+                sequence: SEQUENCE_FINAL,
+                witness: vec![],
             }],
             tx_out_count: 1,
             tx_out: vec![TxOut {
-                value: "s".to_string(),
-                pk_script_bytes: "s".to_string(),
-                pk_script: "s".to_string(),
+                value: 1,
+                pk_script_bytes: 1,
+                pk_script: b"s".to_vec(),
             }],
 
-            lock_time: "s".to_string(),
+            lock_time: 0,
         };
         let tx2 = Transaction {
             version: 1,
             tx_in_count: 1,
             tx_in: vec![TxIn {
-                previous_output: "s".to_string(),
-                script_bytes: "s".to_string(),
-                signature_script: "s".to_string(),
-                sequence: "s".to_string(),
+                previous_output: OutPoint::default(),
+                script_bytes: 1,
+                signature_script: b"s".to_vec(),
+                sequence: SEQUENCE_FINAL,
+                witness: vec![],
             }],
             tx_out_count: 1,
             tx_out: vec![TxOut {
-                value: "s".to_string(),
-                pk_script_bytes: "s".to_string(),
-                pk_script: "s".to_string(),
+                value: 1,
+                pk_script_bytes: 1,
+                pk_script: b"s".to_vec(),
             }],
 
-            lock_time: "s".to_string(),
+            lock_time: 0,
         };
         //트랜잭션 해시 및 직렬화
         let hash_tx1 = MerkleTree::previous_output(&tx1);
@@ -440,10 +855,302 @@ mod tests {
         assert!(hash_tx1 != hash_tx2);
     }
 
+    /*서로 다른 트랜잭션은 해시가 다른지 */
+    #[test]
+    fn test_distinct_transactions_hash_differently() {
+        let tx = |value| Transaction {
+            version: 1,
+            tx_in_count: 1,
+            tx_in: vec![TxIn {
+                previous_output: OutPoint::default(),
+                script_bytes: 1,
+                signature_script: b"s".to_vec(),
+                sequence: SEQUENCE_FINAL,
+                witness: vec![],
+            }],
+            tx_out_count: 1,
+            tx_out: vec![TxOut {
+                value,
+                pk_script_bytes: 1,
+                pk_script: b"s".to_vec(),
+            }],
+            lock_time: 0,
+        };
+        assert_ne!(
+            MerkleTree::previous_output(&tx(1)),
+            MerkleTree::previous_output(&tx(2))
+        );
+    }
+
     #[test]
     fn test2() {
         assert!(1 == 1);
     }
+    /*금액과 스크립트가 값 그대로 직렬화되고 계산되는지 */
+    #[test]
+    fn test_typed_transaction_fields() {
+        let tx = Transaction {
+            version: 2,
+            tx_in_count: 1,
+            tx_in: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: [7; 32],
+                    vout: 1,
+                },
+                script_bytes: 2,
+                signature_script: vec![0x51, 0x52],
+                sequence: 0xfffffffd,
+                witness: vec![],
+            }],
+            tx_out_count: 2,
+            tx_out: vec![
+                TxOut {
+                    value: 50_000,
+                    pk_script_bytes: 1,
+                    pk_script: vec![0x51],
+                },
+                TxOut {
+                    value: 25_000,
+                    pk_script_bytes: 0,
+                    pk_script: vec![],
+                },
+            ],
+            lock_time: 500_000,
+        };
+        assert_eq!(tx.output_value(), Some(75_000));
+
+        let decoded: Transaction = bincode::deserialize(&serialize(&tx).unwrap()).unwrap();
+        assert_eq!(
+            decoded.tx_in[0].previous_output,
+            tx.tx_in[0].previous_output
+        );
+        assert_eq!(decoded.tx_in[0].sequence, 0xfffffffd);
+        assert_eq!(decoded.tx_out[1].value, 25_000);
+        assert_eq!(decoded.lock_time, 500_000);
+
+        let mut overflow = tx;
+        overflow.tx_out[1].value = u64::MAX;
+        assert_eq!(overflow.output_value(), None);
+    }
+    /*txid가 블록 탐색기의 txid와 같고 블록 머클루트가 txid로 만들어지는지 */
+    #[test]
+    fn test_txid_and_block_merkle_root() {
+        for fixture in fixtures::TRANSACTIONS {
+            let tx: Transaction = encode::deserialize(&hex::decode(fixture.hex).unwrap()).unwrap();
+            assert_eq!(to_display_hex(&tx.txid()), fixture.txid, "{}", fixture.name);
+            assert_eq!(MerkleTree::previous_output(&tx), fixture.txid);
+            //witness가 없으면 wtxid와 txid가 같다
+            assert_eq!(tx.wtxid(), tx.txid());
+        }
+
+        let mut block: Block =
+            encode::deserialize(&hex::decode(fixtures::TESTNET_BLOCK_180480).unwrap()).unwrap();
+        assert!(block.check_merkle_root());
+        assert_eq!(block.merkle_tree().num_leaves(), 5);
+        block.txns[3].lock_time += 1;
+        assert!(!block.check_merkle_root());
+    }
+    /*SegWit 블록의 머클루트와 witness commitment가 맞는지 */
+    #[test]
+    fn test_segwit_block_witness_commitment() {
+        let mut block = Block::from_hex(fixtures::TESTNET_SEGWIT_BLOCK).unwrap();
+        assert_eq!(block.tx_count, 15);
+        //코인베이스만 witness reserved value를 가진다
+        assert!(block.txns[0].is_segwit());
+        assert!(block.check_merkle_root());
+        assert!(block.check_witness_commitment());
+
+        //witness를 붙이면 txid 머클루트는 그대로지만 commitment는 깨진다
+        block.txns[1].tx_in[0].witness.push(vec![1]);
+        assert!(block.check_merkle_root());
+        assert!(!block.check_witness_commitment());
+
+        //witness가 없는 블록은 commitment가 없어도 된다
+        let legacy = Block::from_hex(fixtures::TESTNET_BLOCK_180480).unwrap();
+        assert!(legacy.check_witness_commitment());
+    }
+    /*반감기마다 줄어드는 블록 보상 */
+    #[test]
+    fn test_block_subsidy() {
+        assert_eq!(block_subsidy(0), 5_000_000_000);
+        assert_eq!(block_subsidy(209_999), 5_000_000_000);
+        assert_eq!(block_subsidy(210_000), 2_500_000_000);
+        assert_eq!(block_subsidy(840_000), 312_500_000);
+        assert_eq!(block_subsidy(33 * 210_000), 0);
+        assert_eq!(block_subsidy(u32::MAX), 0);
+        //전체 발행량은 2100만 BTC보다 조금 적다
+        let total: u64 = (0..64)
+            .map(|halving| block_subsidy(halving * SUBSIDY_HALVING_INTERVAL) * 210_000)
+            .sum();
+        assert!(total < MAX_MONEY);
+    }
+    /*BIP141 크기, weight, 가상 크기 */
+    #[test]
+    fn test_weight() {
+        for fixture in fixtures::SEGWIT_TRANSACTIONS {
+            let tx = Transaction::from_hex(fixture.hex).unwrap();
+            assert_eq!(tx.total_size(), fixture.hex.len() / 2);
+            assert_eq!(tx.base_size(), fixture.base_size, "{}", fixture.name);
+            assert_eq!(tx.weight(), fixture.weight, "{}", fixture.name);
+            assert_eq!(tx.vsize(), fixture.weight.div_ceil(4));
+        }
+        //witness가 없으면 weight는 크기의 4배
+        for fixture in fixtures::TRANSACTIONS {
+            let tx = Transaction::from_hex(fixture.hex).unwrap();
+            assert_eq!(tx.base_size(), tx.total_size());
+            assert_eq!(tx.vsize(), tx.total_size());
+        }
+
+        let mut block = Block::from_hex(fixtures::TESTNET_SEGWIT_BLOCK).unwrap();
+        assert_eq!(block.total_size(), fixtures::TESTNET_SEGWIT_BLOCK.len() / 2);
+        assert_eq!(block.base_size(), 4283);
+        assert_eq!(block.weight(), 17168);
+        assert!(block.check_weight());
+        let legacy = Block::from_hex(fixtures::TESTNET_BLOCK_180480).unwrap();
+        assert_eq!(
+            legacy.weight(),
+            fixtures::TESTNET_BLOCK_180480.len() / 2 * 4
+        );
+
+        //witness 데이터만으로 4M weight unit을 넘긴 블록
+        block.txns[1].tx_in[0].witness = vec![vec![0; MAX_BLOCK_WEIGHT]];
+        assert!(!block.check_weight());
+    }
+    /*실제 블록의 txid로 계산한 머클루트가 헤더의 머클루트와 같은지 */
+    #[test]
+    fn test_bitcoin_merkle_root_matches_header() {
+        for block in fixtures::BLOCKS {
+            let raw: [u8; 80] = hex::decode(block.header).unwrap().try_into().unwrap();
+            let header = BlockHeaders::from_bytes(&raw);
+            let txids: Vec<Data> = block
+                .txids
+                .iter()
+                .map(|txid| hex::decode(txid).unwrap().into_iter().rev().collect())
+                .collect();
+            let tree = MerkleTree::with_mode(&txids, MerkleMode::Bitcoin);
+            assert_eq!(tree.num_leaves(), block.txids.len(), "{}", block.name);
+            assert_eq!(
+                tree.merkle_root.hash,
+                to_display_hex(&header.merkle_root_hash),
+                "{}",
+                block.name
+            );
+
+            //홀수 레벨에서도 모든 txid의 증명이 검증되는지
+            for (index, txid) in txids.iter().enumerate() {
+                let proof = tree.get_merkle_proof_by_index(index).unwrap();
+                assert!(verify_merkle_proof(
+                    &proof,
+                    txid,
+                    index,
+                    txids.len(),
+                    &tree.root_hash(),
+                    MerkleMode::Bitcoin
+                ));
+            }
+        }
+    }
+    /*마지막 해시 복사 규칙: [a, b, c]와 [a, b, c, c]의 루트가 같은지 */
+    #[test]
+    fn test_bitcoin_odd_level_duplicates_last_hash() {
+        let txids: Vec<Data> = (1..=3u8).map(|i| vec![i; 32]).collect();
+        let mut padded = txids.clone();
+        padded.push(txids[2].clone());
+        let odd = MerkleTree::with_mode(&txids, MerkleMode::Bitcoin);
+        let even = MerkleTree::with_mode(&padded, MerkleMode::Bitcoin);
+        assert_eq!(odd.root_hash(), even.root_hash());
+        assert_eq!(odd.levels, 3);
+    }
+    /*증명이 리프 인덱스에 묶여 있는지 */
+    #[test]
+    fn test_proof_bound_to_leaf_index() {
+        let datas: Vec<Data> = (0..8u8).map(|i| vec![i; 4]).collect();
+        let tree = MerkleTree::new(&datas);
+        let root = tree.root_hash();
+
+        let proof = tree.get_merkle_proof_by_index(3).unwrap();
+        assert_eq!(proof.leaf_index(), 3);
+        assert_eq!(proof.num_leaves(), 8);
+        assert!(verify_merkle_proof(
+            &proof,
+            &datas[3],
+            3,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+        //인덱스 3의 증명을 인덱스 5로 내밀면 거부
+        assert!(!verify_merkle_proof(
+            &proof,
+            &datas[3],
+            5,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+        assert!(!verify_merkle_proof(
+            &proof,
+            &datas[3],
+            3,
+            16,
+            &root,
+            MerkleMode::Legacy
+        ));
+
+        //증명 안의 인덱스를 바꿔도 방향이 맞지 않아 거부
+        let forged = Proof {
+            leaf_index: 5,
+            ..tree.get_merkle_proof_by_index(3).unwrap()
+        };
+        assert!(!verify_merkle_proof(
+            &forged,
+            &datas[3],
+            5,
+            8,
+            &root,
+            MerkleMode::Legacy
+        ));
+    }
+    /*증명 안의 해시 방식이 검증자가 기대하는 방식과 다르면 거부 */
+    #[test]
+    fn test_proof_bound_to_mode() {
+        let txids: Vec<Data> = (0..4u8).map(|i| vec![i; 32]).collect();
+        let tree = MerkleTree::with_mode(&txids, MerkleMode::Taproot);
+        let root = tree.root_hash();
+        let proof = tree.get_merkle_proof_by_index(1).unwrap();
+        assert!(verify_merkle_proof(
+            &proof,
+            &txids[1],
+            1,
+            4,
+            &root,
+            MerkleMode::Taproot
+        ));
+        for mode in [MerkleMode::Bitcoin, MerkleMode::Legacy] {
+            assert!(!verify_merkle_proof(&proof, &txids[1], 1, 4, &root, mode));
+            let swapped = Proof {
+                mode,
+                ..proof.clone()
+            };
+            assert!(!verify_merkle_proof(
+                &swapped,
+                &txids[1],
+                1,
+                4,
+                &root,
+                MerkleMode::Taproot
+            ));
+        }
+        //길이가 잘못된 txid는 패닉 없이 거부
+        assert!(!verify_merkle_proof(
+            &proof,
+            &vec![1; 31],
+            1,
+            4,
+            &root,
+            MerkleMode::Taproot
+        ));
+    }
     #[test]
     fn test3() {
         assert!(1 == 1);
@@ -565,7 +1272,7 @@ proto3를 사용
 블록구조 일치여부
 재계산 block header hash== block header hash
 block timestamp <now()+2hours
-block size <1mb
+block weight <= 4M weight unit (Block::check_weight, SegWit 이전에는 block size <1mb)
 coinbace transaction check
 transaction check
 mempool update  ->데이터베이스 업데이트
@@ -591,11 +1298,13 @@ Block 전파
 트랜잭션 검증
 
 신규 투랜잭션 수신
+(트랜잭션만 보고 하는 검사는 Transaction::check)
 트랜잭션 구조 일치 여부
 in,out list 존재여부
 
-트랜잭션 사이즈 <1mb
+트랜잭션 weight <= 4M weight unit (Transaction::weight)
 output value <2100만 btc
+input 중복 여부, 코인베이스 서명 스크립트 2~100bytes
 mempool존재 여부
 block 존재여부
 input check(double spending)
@@ -620,7 +1329,7 @@ add mempool
 
 블록 구조
 
- Block size   4bytes    <1mb
+ Block size   4bytes    weight <= 4M weight unit
 BlockHeader 80bytes
 Transaction Counter 1~9 bytes
 Transaction variable
@@ -684,6 +1393,7 @@ lockking-script   송금자의 정보가 담긴 데이터
 /*
 Transaction Fee
 input 총힙에서 전체 output의 총합을 뺸 값
+(Transaction::fee에 쓰는 output을 넘겨서 계산, 수수료율은 sat/vB)
 블록에서 설명했듯니 채굴자들이 거래를 더 빠르게 하기 위해서 수수료를 높여야 한다
 
 */
@@ -0,0 +1,1451 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::base64;
+use crate::bip32::{DerivationPath, ExtendedPrivateKey};
+use crate::encode::{self, write_var_bytes, CompactSize, Decodable};
+use crate::error::{BlockchainError, PsbtError};
+use crate::keys::{PrivateKey, PublicKey};
+use crate::locktime::LOCKTIME_THRESHOLD;
+use crate::script::{push_data, witness_program, Instruction, Instructions};
+use crate::sighash::{SIGHASH_ALL, SIGHASH_DEFAULT, SIGHASH_SINGLE};
+use crate::standard::{self, ScriptType};
+use crate::taproot::XOnlyPublicKey;
+use crate::{hash160, sha256, OutPoint, Transaction, TxIn, TxOut, SEQUENCE_FINAL};
+
+/*
+PSBT (BIP174, BIP370)
+서명에 필요한 정보를 트랜잭션과 함께 담아서 여러 참여자가 차례로 채워 가는 형식
+magic("psbt" 0xff) + 전역 맵 + 입력마다 맵 + 출력마다 맵
+맵: (키 길이 | 키 종류 1byte + 키 데이터 | 값 길이 | 값) 반복, 0x00으로 끝난다.
+
+v0: 전역 맵에 서명 안 된 트랜잭션을 통째로 넣는다.
+v2: 트랜잭션 없이 입력의 outpoint, sequence와 출력의 금액, 스크립트를 각 맵에 나눠 넣는다.
+    lock time은 입력들이 요구하는 값에서 정한다.
+안에서는 두 버전을 같은 구조로 다루고 직렬화할 때만 나눈다.
+v0에서는 v2 전용 키 종류도 모르는 키로 보관한다. (BIP174 예제 6)
+
+역할
+Creator: 서명 안 된 트랜잭션으로 만든다. (from_unsigned_tx)
+Updater: 이전 output, redeem/witness 스크립트, 키 출처 같은 서명 정보를 넣는다.
+Signer: 서명할 수 있는 입력에 부분 서명을 넣는다.
+Combiner: 같은 트랜잭션의 PSBT들을 합친다.
+Finalizer: 부분 서명으로 scriptSig, witness를 완성한다.
+Extractor: 완성된 입력으로 네트워크에 보낼 트랜잭션을 꺼낸다.
+
+모르는 키(프로프라이어터리, 해시 preimage, tapscript 경로 정보 등)는 그대로 보관했다가 다시 쓴다.
+맵은 키 순서대로 쓰므로 Bitcoin Core가 만든 PSBT는 같은 바이트로 다시 직렬화된다.
+*/
+const PSBT_MAGIC: &[u8; 5] = b"psbt\xff";
+const PSBT_SEPARATOR: u8 = 0x00;
+
+const PSBT_GLOBAL_UNSIGNED_TX: u8 = 0x00;
+const PSBT_GLOBAL_XPUB: u8 = 0x01;
+const PSBT_GLOBAL_TX_VERSION: u8 = 0x02;
+const PSBT_GLOBAL_FALLBACK_LOCKTIME: u8 = 0x03;
+const PSBT_GLOBAL_INPUT_COUNT: u8 = 0x04;
+const PSBT_GLOBAL_OUTPUT_COUNT: u8 = 0x05;
+const PSBT_GLOBAL_TX_MODIFIABLE: u8 = 0x06;
+const PSBT_GLOBAL_VERSION: u8 = 0xfb;
+
+const PSBT_IN_NON_WITNESS_UTXO: u8 = 0x00;
+const PSBT_IN_WITNESS_UTXO: u8 = 0x01;
+const PSBT_IN_PARTIAL_SIG: u8 = 0x02;
+const PSBT_IN_SIGHASH_TYPE: u8 = 0x03;
+const PSBT_IN_REDEEM_SCRIPT: u8 = 0x04;
+const PSBT_IN_WITNESS_SCRIPT: u8 = 0x05;
+const PSBT_IN_BIP32_DERIVATION: u8 = 0x06;
+const PSBT_IN_FINAL_SCRIPTSIG: u8 = 0x07;
+const PSBT_IN_FINAL_SCRIPTWITNESS: u8 = 0x08;
+const PSBT_IN_PREVIOUS_TXID: u8 = 0x0e;
+const PSBT_IN_OUTPUT_INDEX: u8 = 0x0f;
+const PSBT_IN_SEQUENCE: u8 = 0x10;
+const PSBT_IN_REQUIRED_TIME_LOCKTIME: u8 = 0x11;
+const PSBT_IN_REQUIRED_HEIGHT_LOCKTIME: u8 = 0x12;
+const PSBT_IN_TAP_KEY_SIG: u8 = 0x13;
+const PSBT_IN_TAP_INTERNAL_KEY: u8 = 0x17;
+const PSBT_IN_TAP_MERKLE_ROOT: u8 = 0x18;
+
+const PSBT_OUT_REDEEM_SCRIPT: u8 = 0x00;
+const PSBT_OUT_WITNESS_SCRIPT: u8 = 0x01;
+const PSBT_OUT_BIP32_DERIVATION: u8 = 0x02;
+const PSBT_OUT_AMOUNT: u8 = 0x03;
+const PSBT_OUT_SCRIPT: u8 = 0x04;
+const PSBT_OUT_TAP_INTERNAL_KEY: u8 = 0x05;
+
+/// 키 종류 1byte를 포함한 키 → 값
+type Map = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// 키를 만든 확장 키의 fingerprint와 유도 경로
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySource {
+    pub fingerprint: [u8; 4],
+    pub path: DerivationPath,
+}
+
+impl KeySource {
+    /// fingerprint 4bytes + 자식 번호마다 u32 (리틀 엔디언)
+    fn serialize(&self) -> Vec<u8> {
+        let mut data = self.fingerprint.to_vec();
+        for index in &self.path.0 {
+            data.extend_from_slice(&index.to_le_bytes());
+        }
+        data
+    }
+
+    fn parse(value: &[u8], key_type: u8) -> Result<KeySource, PsbtError> {
+        if value.len() < 4 || !value.len().is_multiple_of(4) {
+            return Err(PsbtError::InvalidValue(key_type));
+        }
+        Ok(KeySource {
+            fingerprint: value[..4].try_into().unwrap(),
+            path: DerivationPath(
+                value[4..]
+                    .chunks(4)
+                    .map(|index| u32::from_le_bytes(index.try_into().unwrap()))
+                    .collect(),
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Psbt {
+    /// 0 또는 2
+    pub version: u32,
+    pub tx_version: i32,
+    /// v0에서는 unsigned tx의 lock_time, v2에서는 입력들이 lock time을 요구하지 않을 때 쓰는 값
+    pub fallback_lock_time: Option<u32>,
+    /// v2 전용, bit 0: 입력 추가 가능, bit 1: 출력 추가 가능, bit 2: SIGHASH_SINGLE 서명 있음
+    pub tx_modifiable: Option<u8>,
+    /// 직렬화한 확장 공개키(78bytes) → 키 출처
+    pub xpubs: BTreeMap<Vec<u8>, KeySource>,
+    pub inputs: Vec<PsbtInput>,
+    pub outputs: Vec<PsbtOutput>,
+    pub unknown: Map,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PsbtInput {
+    pub previous_output: OutPoint,
+    /// v2에서 없으면 SEQUENCE_FINAL
+    pub sequence: Option<u32>,
+    /// v2 전용
+    pub required_time_lock_time: Option<u32>,
+    /// v2 전용
+    pub required_height_lock_time: Option<u32>,
+    /// 이 입력이 쓰는 output이 들어 있는 이전 트랜잭션
+    pub non_witness_utxo: Option<Transaction>,
+    /// 이 입력이 쓰는 output (SegWit, Taproot)
+    pub witness_utxo: Option<TxOut>,
+    /// 공개키 → sighash 타입이 붙은 서명
+    pub partial_sigs: BTreeMap<Vec<u8>, Vec<u8>>,
+    pub sighash_type: Option<u32>,
+    pub redeem_script: Option<Vec<u8>>,
+    pub witness_script: Option<Vec<u8>>,
+    /// 공개키 → 키 출처
+    pub bip32_derivation: BTreeMap<Vec<u8>, KeySource>,
+    pub final_script_sig: Option<Vec<u8>>,
+    pub final_script_witness: Option<Vec<Vec<u8>>>,
+    /// 키 경로 Schnorr 서명 (64bytes, SIGHASH_DEFAULT가 아니면 65bytes)
+    pub tap_key_sig: Option<Vec<u8>>,
+    pub tap_internal_key: Option<[u8; 32]>,
+    pub tap_merkle_root: Option<[u8; 32]>,
+    pub unknown: Map,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PsbtOutput {
+    pub amount: u64,
+    pub script: Vec<u8>,
+    pub redeem_script: Option<Vec<u8>>,
+    pub witness_script: Option<Vec<u8>>,
+    pub bip32_derivation: BTreeMap<Vec<u8>, KeySource>,
+    pub tap_internal_key: Option<[u8; 32]>,
+    pub unknown: Map,
+}
+
+fn read_map(reader: &mut &[u8]) -> Result<Map, PsbtError> {
+    let mut map = Map::new();
+    loop {
+        let key = encode::read_var_bytes(reader)?;
+        if key.is_empty() {
+            return Ok(map);
+        }
+        let value = encode::read_var_bytes(reader)?;
+        if map.contains_key(&key) {
+            return Err(PsbtError::DuplicateKey(key));
+        }
+        map.insert(key, value);
+    }
+}
+
+fn write_map(writer: &mut Vec<u8>, map: &Map) {
+    for (key, value) in map {
+        write_var_bytes(writer, key);
+        write_var_bytes(writer, value);
+    }
+    writer.push(PSBT_SEPARATOR);
+}
+
+fn insert(map: &mut Map, key_type: u8, key_data: &[u8], value: Vec<u8>) {
+    map.insert([&[key_type], key_data].concat(), value);
+}
+
+/// 키 데이터가 없는 키 종류
+fn expect_single(key: &[u8]) -> Result<(), PsbtError> {
+    if key.len() != 1 {
+        return Err(PsbtError::InvalidKey(key.to_vec()));
+    }
+    Ok(())
+}
+
+fn fixed<const N: usize>(value: &[u8], key_type: u8) -> Result<[u8; N], PsbtError> {
+    value
+        .try_into()
+        .map_err(|_| PsbtError::InvalidValue(key_type))
+}
+
+fn parse_u32(value: &[u8], key_type: u8) -> Result<u32, PsbtError> {
+    Ok(u32::from_le_bytes(fixed(value, key_type)?))
+}
+
+fn parse_pubkey(key: &[u8]) -> Result<Vec<u8>, PsbtError> {
+    PublicKey::from_slice(&key[1..]).map_err(|_| PsbtError::InvalidKey(key.to_vec()))?;
+    Ok(key[1..].to_vec())
+}
+
+fn parse_x_only(value: &[u8], key_type: u8) -> Result<[u8; 32], PsbtError> {
+    XOnlyPublicKey::from_slice(value).map_err(|_| PsbtError::InvalidValue(key_type))?;
+    fixed(value, key_type)
+}
+
+/// witness 스택: 항목 수 + 길이가 붙은 항목들
+fn serialize_witness(witness: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = encode::serialize(&CompactSize(witness.len() as u64));
+    for item in witness {
+        write_var_bytes(&mut data, item);
+    }
+    data
+}
+
+fn parse_witness(value: &[u8], key_type: u8) -> Result<Vec<Vec<u8>>, PsbtError> {
+    let mut reader = value;
+    let count = CompactSize::consensus_decode(&mut reader)?.0;
+    let mut witness = vec![];
+    for _ in 0..count {
+        witness.push(encode::read_var_bytes(&mut reader)?);
+    }
+    if !reader.is_empty() {
+        return Err(PsbtError::InvalidValue(key_type));
+    }
+    Ok(witness)
+}
+
+/// 다른 PSBT의 값은 이쪽에 없는 키만 넣는다.
+fn merge_map<V>(map: &mut BTreeMap<Vec<u8>, V>, other: BTreeMap<Vec<u8>, V>) {
+    for (key, value) in other {
+        map.entry(key).or_insert(value);
+    }
+}
+
+/// 스크립트에 이 공개키가 푸시되어 있는지
+fn has_pubkey(script: &[u8], pubkey: &[u8]) -> bool {
+    Instructions::new(script).any(|instruction| instruction == Ok(Instruction::PushBytes(pubkey)))
+}
+
+/// 스택 항목들을 푸시하는 scriptSig
+fn push_stack(stack: &[Vec<u8>]) -> Vec<u8> {
+    stack.iter().flat_map(|item| push_data(item)).collect()
+}
+
+impl PsbtInput {
+    fn parse(map: Map, version: u32) -> Result<PsbtInput, PsbtError> {
+        let mut input = PsbtInput::default();
+        let (mut previous_txid, mut output_index) = (None, None);
+        for (key, value) in map {
+            let key_type = key[0];
+            match key_type {
+                PSBT_IN_NON_WITNESS_UTXO => {
+                    expect_single(&key)?;
+                    input.non_witness_utxo = Some(encode::deserialize(&value)?);
+                }
+                PSBT_IN_WITNESS_UTXO => {
+                    expect_single(&key)?;
+                    input.witness_utxo = Some(encode::deserialize(&value)?);
+                }
+                PSBT_IN_PARTIAL_SIG => {
+                    input.partial_sigs.insert(parse_pubkey(&key)?, value);
+                }
+                PSBT_IN_SIGHASH_TYPE => {
+                    expect_single(&key)?;
+                    input.sighash_type = Some(parse_u32(&value, key_type)?);
+                }
+                PSBT_IN_REDEEM_SCRIPT => {
+                    expect_single(&key)?;
+                    input.redeem_script = Some(value);
+                }
+                PSBT_IN_WITNESS_SCRIPT => {
+                    expect_single(&key)?;
+                    input.witness_script = Some(value);
+                }
+                PSBT_IN_BIP32_DERIVATION => {
+                    input
+                        .bip32_derivation
+                        .insert(parse_pubkey(&key)?, KeySource::parse(&value, key_type)?);
+                }
+                PSBT_IN_FINAL_SCRIPTSIG => {
+                    expect_single(&key)?;
+                    input.final_script_sig = Some(value);
+                }
+                PSBT_IN_FINAL_SCRIPTWITNESS => {
+                    expect_single(&key)?;
+                    input.final_script_witness = Some(parse_witness(&value, key_type)?);
+                }
+                PSBT_IN_PREVIOUS_TXID if version == 2 => {
+                    expect_single(&key)?;
+                    previous_txid = Some(fixed(&value, key_type)?);
+                }
+                PSBT_IN_OUTPUT_INDEX if version == 2 => {
+                    expect_single(&key)?;
+                    output_index = Some(parse_u32(&value, key_type)?);
+                }
+                PSBT_IN_SEQUENCE if version == 2 => {
+                    expect_single(&key)?;
+                    input.sequence = Some(parse_u32(&value, key_type)?);
+                }
+                PSBT_IN_REQUIRED_TIME_LOCKTIME if version == 2 => {
+                    expect_single(&key)?;
+                    let lock_time = parse_u32(&value, key_type)?;
+                    if lock_time < LOCKTIME_THRESHOLD {
+                        return Err(PsbtError::InvalidValue(key_type));
+                    }
+                    input.required_time_lock_time = Some(lock_time);
+                }
+                PSBT_IN_REQUIRED_HEIGHT_LOCKTIME if version == 2 => {
+                    expect_single(&key)?;
+                    let lock_time = parse_u32(&value, key_type)?;
+                    if lock_time == 0 || lock_time >= LOCKTIME_THRESHOLD {
+                        return Err(PsbtError::InvalidValue(key_type));
+                    }
+                    input.required_height_lock_time = Some(lock_time);
+                }
+                PSBT_IN_TAP_KEY_SIG => {
+                    expect_single(&key)?;
+                    if value.len() != 64 && value.len() != 65 {
+                        return Err(PsbtError::InvalidValue(key_type));
+                    }
+                    input.tap_key_sig = Some(value);
+                }
+                PSBT_IN_TAP_INTERNAL_KEY => {
+                    expect_single(&key)?;
+                    input.tap_internal_key = Some(parse_x_only(&value, key_type)?);
+                }
+                PSBT_IN_TAP_MERKLE_ROOT => {
+                    expect_single(&key)?;
+                    input.tap_merkle_root = Some(fixed(&value, key_type)?);
+                }
+                _ => {
+                    input.unknown.insert(key, value);
+                }
+            }
+        }
+        if version == 2 {
+            input.previous_output = OutPoint {
+                txid: previous_txid.ok_or(PsbtError::MissingField("PSBT_IN_PREVIOUS_TXID"))?,
+                vout: output_index.ok_or(PsbtError::MissingField("PSBT_IN_OUTPUT_INDEX"))?,
+            };
+        }
+        Ok(input)
+    }
+
+    fn to_map(&self, version: u32) -> Map {
+        let mut map = self.unknown.clone();
+        if let Some(tx) = &self.non_witness_utxo {
+            insert(
+                &mut map,
+                PSBT_IN_NON_WITNESS_UTXO,
+                &[],
+                encode::serialize(tx),
+            );
+        }
+        if let Some(output) = &self.witness_utxo {
+            insert(
+                &mut map,
+                PSBT_IN_WITNESS_UTXO,
+                &[],
+                encode::serialize(output),
+            );
+        }
+        for (pubkey, signature) in &self.partial_sigs {
+            insert(&mut map, PSBT_IN_PARTIAL_SIG, pubkey, signature.clone());
+        }
+        if let Some(sighash_type) = self.sighash_type {
+            insert(
+                &mut map,
+                PSBT_IN_SIGHASH_TYPE,
+                &[],
+                sighash_type.to_le_bytes().to_vec(),
+            );
+        }
+        if let Some(script) = &self.redeem_script {
+            insert(&mut map, PSBT_IN_REDEEM_SCRIPT, &[], script.clone());
+        }
+        if let Some(script) = &self.witness_script {
+            insert(&mut map, PSBT_IN_WITNESS_SCRIPT, &[], script.clone());
+        }
+        for (pubkey, source) in &self.bip32_derivation {
+            insert(
+                &mut map,
+                PSBT_IN_BIP32_DERIVATION,
+                pubkey,
+                source.serialize(),
+            );
+        }
+        if let Some(script) = &self.final_script_sig {
+            insert(&mut map, PSBT_IN_FINAL_SCRIPTSIG, &[], script.clone());
+        }
+        if let Some(witness) = &self.final_script_witness {
+            insert(
+                &mut map,
+                PSBT_IN_FINAL_SCRIPTWITNESS,
+                &[],
+                serialize_witness(witness),
+            );
+        }
+        if version == 2 {
+            let outpoint = &self.previous_output;
+            insert(&mut map, PSBT_IN_PREVIOUS_TXID, &[], outpoint.txid.to_vec());
+            insert(
+                &mut map,
+                PSBT_IN_OUTPUT_INDEX,
+                &[],
+                outpoint.vout.to_le_bytes().to_vec(),
+            );
+            if let Some(sequence) = self.sequence {
+                insert(
+                    &mut map,
+                    PSBT_IN_SEQUENCE,
+                    &[],
+                    sequence.to_le_bytes().to_vec(),
+                );
+            }
+            if let Some(lock_time) = self.required_time_lock_time {
+                let value = lock_time.to_le_bytes().to_vec();
+                insert(&mut map, PSBT_IN_REQUIRED_TIME_LOCKTIME, &[], value);
+            }
+            if let Some(lock_time) = self.required_height_lock_time {
+                let value = lock_time.to_le_bytes().to_vec();
+                insert(&mut map, PSBT_IN_REQUIRED_HEIGHT_LOCKTIME, &[], value);
+            }
+        }
+        if let Some(signature) = &self.tap_key_sig {
+            insert(&mut map, PSBT_IN_TAP_KEY_SIG, &[], signature.clone());
+        }
+        if let Some(key) = self.tap_internal_key {
+            insert(&mut map, PSBT_IN_TAP_INTERNAL_KEY, &[], key.to_vec());
+        }
+        if let Some(root) = self.tap_merkle_root {
+            insert(&mut map, PSBT_IN_TAP_MERKLE_ROOT, &[], root.to_vec());
+        }
+        map
+    }
+
+    fn merge(&mut self, other: PsbtInput) {
+        self.non_witness_utxo = self.non_witness_utxo.take().or(other.non_witness_utxo);
+        self.witness_utxo = self.witness_utxo.take().or(other.witness_utxo);
+        merge_map(&mut self.partial_sigs, other.partial_sigs);
+        self.sighash_type = self.sighash_type.or(other.sighash_type);
+        self.redeem_script = self.redeem_script.take().or(other.redeem_script);
+        self.witness_script = self.witness_script.take().or(other.witness_script);
+        merge_map(&mut self.bip32_derivation, other.bip32_derivation);
+        self.final_script_sig = self.final_script_sig.take().or(other.final_script_sig);
+        self.final_script_witness = self
+            .final_script_witness
+            .take()
+            .or(other.final_script_witness);
+        self.required_time_lock_time = self
+            .required_time_lock_time
+            .or(other.required_time_lock_time);
+        self.required_height_lock_time = self
+            .required_height_lock_time
+            .or(other.required_height_lock_time);
+        self.tap_key_sig = self.tap_key_sig.take().or(other.tap_key_sig);
+        self.tap_internal_key = self.tap_internal_key.or(other.tap_internal_key);
+        self.tap_merkle_root = self.tap_merkle_root.or(other.tap_merkle_root);
+        merge_map(&mut self.unknown, other.unknown);
+    }
+
+    fn is_finalized(&self) -> bool {
+        self.final_script_sig.is_some() || self.final_script_witness.is_some()
+    }
+
+    /// 스크립트를 푸는 스택, 서명이 모자라면 None
+    fn satisfy(&self, script: &[u8]) -> Option<Vec<Vec<u8>>> {
+        match standard::classify(script) {
+            ScriptType::PubKey(pubkey) => Some(vec![self.partial_sigs.get(&pubkey)?.clone()]),
+            ScriptType::PubKeyHash(hash) | ScriptType::WitnessPubKeyHash(hash) => {
+                let (pubkey, signature) = self
+                    .partial_sigs
+                    .iter()
+                    .find(|(pubkey, _)| hash160(pubkey)[..] == hash[..])?;
+                Some(vec![signature.clone(), pubkey.clone()])
+            }
+            ScriptType::Multisig { required, pubkeys } => {
+                // 서명은 공개키 순서대로 넣어야 한다.
+                let signatures: Vec<Vec<u8>> = pubkeys
+                    .iter()
+                    .filter_map(|pubkey| self.partial_sigs.get(pubkey).cloned())
+                    .take(required)
+                    .collect();
+                if signatures.len() < required {
+                    return None;
+                }
+                // CHECKMULTISIG가 하나 더 꺼내는 빈 값
+                Some([vec![vec![]], signatures].concat())
+            }
+            _ => None,
+        }
+    }
+
+    /// P2WPKH, P2WSH 프로그램을 푸는 witness
+    fn satisfy_witness(&self, program: &[u8]) -> Option<Vec<Vec<u8>>> {
+        match standard::classify(program) {
+            ScriptType::WitnessPubKeyHash(_) => self.satisfy(program),
+            ScriptType::WitnessScriptHash(_) => {
+                let witness_script = self.witness_script.as_ref()?;
+                let mut witness = self.satisfy(witness_script)?;
+                witness.push(witness_script.clone());
+                Some(witness)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl PsbtOutput {
+    fn parse(map: Map, version: u32) -> Result<PsbtOutput, PsbtError> {
+        let mut output = PsbtOutput::default();
+        let (mut amount, mut script) = (None, None);
+        for (key, value) in map {
+            let key_type = key[0];
+            match key_type {
+                PSBT_OUT_REDEEM_SCRIPT => {
+                    expect_single(&key)?;
+                    output.redeem_script = Some(value);
+                }
+                PSBT_OUT_WITNESS_SCRIPT => {
+                    expect_single(&key)?;
+                    output.witness_script = Some(value);
+                }
+                PSBT_OUT_BIP32_DERIVATION => {
+                    output
+                        .bip32_derivation
+                        .insert(parse_pubkey(&key)?, KeySource::parse(&value, key_type)?);
+                }
+                PSBT_OUT_AMOUNT if version == 2 => {
+                    expect_single(&key)?;
+                    amount = Some(u64::from_le_bytes(fixed(&value, key_type)?));
+                }
+                PSBT_OUT_SCRIPT if version == 2 => {
+                    expect_single(&key)?;
+                    script = Some(value);
+                }
+                PSBT_OUT_TAP_INTERNAL_KEY => {
+                    expect_single(&key)?;
+                    output.tap_internal_key = Some(parse_x_only(&value, key_type)?);
+                }
+                _ => {
+                    output.unknown.insert(key, value);
+                }
+            }
+        }
+        if version == 2 {
+            output.amount = amount.ok_or(PsbtError::MissingField("PSBT_OUT_AMOUNT"))?;
+            output.script = script.ok_or(PsbtError::MissingField("PSBT_OUT_SCRIPT"))?;
+        }
+        Ok(output)
+    }
+
+    fn to_map(&self, version: u32) -> Map {
+        let mut map = self.unknown.clone();
+        if let Some(script) = &self.redeem_script {
+            insert(&mut map, PSBT_OUT_REDEEM_SCRIPT, &[], script.clone());
+        }
+        if let Some(script) = &self.witness_script {
+            insert(&mut map, PSBT_OUT_WITNESS_SCRIPT, &[], script.clone());
+        }
+        for (pubkey, source) in &self.bip32_derivation {
+            insert(
+                &mut map,
+                PSBT_OUT_BIP32_DERIVATION,
+                pubkey,
+                source.serialize(),
+            );
+        }
+        if version == 2 {
+            insert(
+                &mut map,
+                PSBT_OUT_AMOUNT,
+                &[],
+                self.amount.to_le_bytes().to_vec(),
+            );
+            insert(&mut map, PSBT_OUT_SCRIPT, &[], self.script.clone());
+        }
+        if let Some(key) = self.tap_internal_key {
+            insert(&mut map, PSBT_OUT_TAP_INTERNAL_KEY, &[], key.to_vec());
+        }
+        map
+    }
+
+    fn merge(&mut self, other: PsbtOutput) {
+        self.redeem_script = self.redeem_script.take().or(other.redeem_script);
+        self.witness_script = self.witness_script.take().or(other.witness_script);
+        merge_map(&mut self.bip32_derivation, other.bip32_derivation);
+        self.tap_internal_key = self.tap_internal_key.or(other.tap_internal_key);
+        merge_map(&mut self.unknown, other.unknown);
+    }
+}
+
+impl Psbt {
+    /// Creator: 서명 안 된 트랜잭션으로 v0 PSBT를 만든다.
+    pub fn from_unsigned_tx(tx: Transaction) -> Result<Psbt, PsbtError> {
+        if tx
+            .tx_in
+            .iter()
+            .any(|input| !input.signature_script.is_empty() || !input.witness.is_empty())
+        {
+            return Err(PsbtError::UnsignedTxHasScripts);
+        }
+        Ok(Psbt {
+            version: 0,
+            tx_version: tx.version,
+            fallback_lock_time: Some(tx.lock_time),
+            inputs: tx
+                .tx_in
+                .into_iter()
+                .map(|input| PsbtInput {
+                    previous_output: input.previous_output,
+                    sequence: Some(input.sequence),
+                    ..Default::default()
+                })
+                .collect(),
+            outputs: tx
+                .tx_out
+                .into_iter()
+                .map(|output| PsbtOutput {
+                    amount: output.value,
+                    script: output.pk_script,
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    /*
+    다른 버전으로 바꾼 PSBT
+    v2 → v0: 정한 lock time을 트랜잭션에 넣고 v2 전용 값(입력의 lock time 요구, tx_modifiable)은 버린다.
+    */
+    pub fn to_version(&self, version: u32) -> Result<Psbt, PsbtError> {
+        if version != 0 && version != 2 {
+            return Err(PsbtError::UnsupportedVersion(version));
+        }
+        let mut psbt = self.clone();
+        if version == 0 {
+            psbt.fallback_lock_time = Some(self.lock_time()?);
+            psbt.tx_modifiable = None;
+            for input in psbt.inputs.iter_mut() {
+                input.sequence = Some(input.sequence.unwrap_or(SEQUENCE_FINAL));
+                input.required_time_lock_time = None;
+                input.required_height_lock_time = None;
+            }
+        }
+        psbt.version = version;
+        Ok(psbt)
+    }
+
+    /*
+    트랜잭션의 lock time
+    v0: unsigned tx의 값
+    v2 (BIP370): lock time을 요구하는 입력이 없으면 fallback(없으면 0)
+    있으면 요구하는 입력 모두가 받아들이는 종류(높이를 먼저)에서 가장 큰 값
+    */
+    pub fn lock_time(&self) -> Result<u32, PsbtError> {
+        let fallback = self.fallback_lock_time.unwrap_or(0);
+        if self.version == 0 {
+            return Ok(fallback);
+        }
+        let requiring: Vec<&PsbtInput> = self
+            .inputs
+            .iter()
+            .filter(|input| {
+                input.required_time_lock_time.is_some() || input.required_height_lock_time.is_some()
+            })
+            .collect();
+        if requiring.is_empty() {
+            return Ok(fallback);
+        }
+        if let Some(heights) = requiring
+            .iter()
+            .map(|input| input.required_height_lock_time)
+            .collect::<Option<Vec<u32>>>()
+        {
+            return Ok(heights.into_iter().max().unwrap());
+        }
+        requiring
+            .iter()
+            .map(|input| input.required_time_lock_time)
+            .collect::<Option<Vec<u32>>>()
+            .map(|times| times.into_iter().max().unwrap())
+            .ok_or(PsbtError::LockTimeConflict)
+    }
+
+    pub fn unsigned_tx(&self) -> Result<Transaction, PsbtError> {
+        let tx_in: Vec<TxIn> = self
+            .inputs
+            .iter()
+            .map(|input| TxIn {
+                previous_output: input.previous_output,
+                script_bytes: 0,
+                signature_script: vec![],
+                sequence: input.sequence.unwrap_or(SEQUENCE_FINAL),
+                witness: vec![],
+            })
+            .collect();
+        let tx_out: Vec<TxOut> = self
+            .outputs
+            .iter()
+            .map(|output| TxOut {
+                value: output.amount,
+                pk_script_bytes: output.script.len(),
+                pk_script: output.script.clone(),
+            })
+            .collect();
+        Ok(Transaction {
+            version: self.tx_version,
+            tx_in_count: tx_in.len(),
+            tx_in,
+            tx_out_count: tx_out.len(),
+            tx_out,
+            lock_time: self.lock_time()?,
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut global = self.unknown.clone();
+        for (xpub, source) in &self.xpubs {
+            insert(&mut global, PSBT_GLOBAL_XPUB, xpub, source.serialize());
+        }
+        if self.version == 0 {
+            let tx = self
+                .unsigned_tx()
+                .expect("v0 lock time comes from the transaction");
+            let value = encode::serialize_without_witness(&tx);
+            insert(&mut global, PSBT_GLOBAL_UNSIGNED_TX, &[], value);
+        } else {
+            let version = self.tx_version.to_le_bytes().to_vec();
+            insert(&mut global, PSBT_GLOBAL_TX_VERSION, &[], version);
+            if let Some(lock_time) = self.fallback_lock_time {
+                let value = lock_time.to_le_bytes().to_vec();
+                insert(&mut global, PSBT_GLOBAL_FALLBACK_LOCKTIME, &[], value);
+            }
+            let input_count = encode::serialize(&CompactSize(self.inputs.len() as u64));
+            insert(&mut global, PSBT_GLOBAL_INPUT_COUNT, &[], input_count);
+            let output_count = encode::serialize(&CompactSize(self.outputs.len() as u64));
+            insert(&mut global, PSBT_GLOBAL_OUTPUT_COUNT, &[], output_count);
+            if let Some(flags) = self.tx_modifiable {
+                insert(&mut global, PSBT_GLOBAL_TX_MODIFIABLE, &[], vec![flags]);
+            }
+            let version = self.version.to_le_bytes().to_vec();
+            insert(&mut global, PSBT_GLOBAL_VERSION, &[], version);
+        }
+
+        let mut data = PSBT_MAGIC.to_vec();
+        write_map(&mut data, &global);
+        for input in &self.inputs {
+            write_map(&mut data, &input.to_map(self.version));
+        }
+        for output in &self.outputs {
+            write_map(&mut data, &output.to_map(self.version));
+        }
+        data
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<Psbt, PsbtError> {
+        let mut reader = data
+            .strip_prefix(PSBT_MAGIC)
+            .ok_or(PsbtError::InvalidMagic)?;
+        let global = read_map(&mut reader)?;
+        let version = match global.get(&[PSBT_GLOBAL_VERSION][..]) {
+            Some(value) => parse_u32(value, PSBT_GLOBAL_VERSION)?,
+            None => 0,
+        };
+        if version != 0 && version != 2 {
+            return Err(PsbtError::UnsupportedVersion(version));
+        }
+
+        let mut psbt = Psbt {
+            version,
+            ..Default::default()
+        };
+        let (mut unsigned_tx, mut tx_version) = (None, None);
+        let (mut input_count, mut output_count) = (None, None);
+        for (key, value) in global {
+            let key_type = key[0];
+            match key_type {
+                PSBT_GLOBAL_UNSIGNED_TX => {
+                    expect_single(&key)?;
+                    if version != 0 {
+                        return Err(PsbtError::FieldNotAllowed {
+                            field: "PSBT_GLOBAL_UNSIGNED_TX",
+                            version,
+                        });
+                    }
+                    unsigned_tx = Some(encode::deserialize_without_witness(&value)?);
+                }
+                PSBT_GLOBAL_XPUB => {
+                    if key.len() != 79 {
+                        return Err(PsbtError::InvalidKey(key));
+                    }
+                    let source = KeySource::parse(&value, key_type)?;
+                    psbt.xpubs.insert(key[1..].to_vec(), source);
+                }
+                PSBT_GLOBAL_TX_VERSION if version == 2 => {
+                    expect_single(&key)?;
+                    tx_version = Some(i32::from_le_bytes(fixed(&value, key_type)?));
+                }
+                PSBT_GLOBAL_FALLBACK_LOCKTIME if version == 2 => {
+                    expect_single(&key)?;
+                    psbt.fallback_lock_time = Some(parse_u32(&value, key_type)?);
+                }
+                PSBT_GLOBAL_INPUT_COUNT if version == 2 => {
+                    expect_single(&key)?;
+                    input_count = Some(encode::deserialize::<CompactSize>(&value)?.0);
+                }
+                PSBT_GLOBAL_OUTPUT_COUNT if version == 2 => {
+                    expect_single(&key)?;
+                    output_count = Some(encode::deserialize::<CompactSize>(&value)?.0);
+                }
+                PSBT_GLOBAL_TX_MODIFIABLE if version == 2 => {
+                    expect_single(&key)?;
+                    psbt.tx_modifiable = Some(fixed::<1>(&value, key_type)?[0]);
+                }
+                PSBT_GLOBAL_VERSION => expect_single(&key)?,
+                _ => {
+                    psbt.unknown.insert(key, value);
+                }
+            }
+        }
+
+        if version == 0 {
+            let tx: Transaction =
+                unsigned_tx.ok_or(PsbtError::MissingField("PSBT_GLOBAL_UNSIGNED_TX"))?;
+            if tx
+                .tx_in
+                .iter()
+                .any(|input| !input.signature_script.is_empty())
+            {
+                return Err(PsbtError::UnsignedTxHasScripts);
+            }
+            psbt.tx_version = tx.version;
+            psbt.fallback_lock_time = Some(tx.lock_time);
+            for tx_in in &tx.tx_in {
+                let mut input = PsbtInput::parse(read_map(&mut reader)?, version)?;
+                input.previous_output = tx_in.previous_output;
+                input.sequence = Some(tx_in.sequence);
+                psbt.inputs.push(input);
+            }
+            for tx_out in tx.tx_out {
+                let mut output = PsbtOutput::parse(read_map(&mut reader)?, version)?;
+                output.amount = tx_out.value;
+                output.script = tx_out.pk_script;
+                psbt.outputs.push(output);
+            }
+        } else {
+            psbt.tx_version =
+                tx_version.ok_or(PsbtError::MissingField("PSBT_GLOBAL_TX_VERSION"))?;
+            let input_count =
+                input_count.ok_or(PsbtError::MissingField("PSBT_GLOBAL_INPUT_COUNT"))?;
+            let output_count =
+                output_count.ok_or(PsbtError::MissingField("PSBT_GLOBAL_OUTPUT_COUNT"))?;
+            // 개수만 크고 맵이 없는 입력에 미리 메모리를 잡지 않도록 하나씩 읽는다.
+            for _ in 0..input_count {
+                psbt.inputs
+                    .push(PsbtInput::parse(read_map(&mut reader)?, version)?);
+            }
+            for _ in 0..output_count {
+                psbt.outputs
+                    .push(PsbtOutput::parse(read_map(&mut reader)?, version)?);
+            }
+        }
+        if !reader.is_empty() {
+            return Err(PsbtError::Encoding(BlockchainError::TrailingBytes(
+                reader.len(),
+            )));
+        }
+
+        for (index, input) in psbt.inputs.iter().enumerate() {
+            if let Some(prev_tx) = &input.non_witness_utxo {
+                if prev_tx.txid() != input.previous_output.txid {
+                    return Err(PsbtError::PrevTxMismatch(index));
+                }
+            }
+        }
+        Ok(psbt)
+    }
+
+    /// 입력이 쓰는 output (witness_utxo가 없으면 non_witness_utxo에서 찾는다)
+    pub fn spent_output(&self, index: usize) -> Option<TxOut> {
+        let input = self.inputs.get(index)?;
+        input.witness_utxo.clone().or_else(|| {
+            input
+                .non_witness_utxo
+                .as_ref()?
+                .tx_out
+                .get(input.previous_output.vout as usize)
+                .cloned()
+        })
+    }
+
+    /// Updater: 입력이 쓰는 이전 트랜잭션을 넣는다. 쓰는 output이 witness 프로그램이면 witness_utxo도 넣는다.
+    pub fn set_prev_tx(&mut self, index: usize, prev_tx: Transaction) -> Result<(), PsbtError> {
+        let input = self
+            .inputs
+            .get_mut(index)
+            .ok_or(PsbtError::InputIndexOutOfRange(index))?;
+        let output = prev_tx
+            .tx_out
+            .get(input.previous_output.vout as usize)
+            .filter(|_| prev_tx.txid() == input.previous_output.txid)
+            .ok_or(PsbtError::PrevTxMismatch(index))?;
+        if witness_program(&output.pk_script).is_some() {
+            input.witness_utxo = Some(output.clone());
+        }
+        input.non_witness_utxo = Some(prev_tx);
+        Ok(())
+    }
+
+    /*
+    Signer
+    key로 서명할 수 있는 입력마다 서명을 넣고 서명한 입력 수를 반환한다.
+    P2PKH, P2PK, 멀티시그, P2WPKH, P2WSH와 P2SH로 감싼 것은 ECDSA 부분 서명을,
+    Taproot는 tap_internal_key가 이 키인 입력에 키 경로 서명을 넣는다. (aux_rand 0)
+    sighash 타입이 없으면 SIGHASH_ALL (Taproot는 SIGHASH_DEFAULT)
+    sighash 타입은 서명 뒤에 1byte로 붙으므로 1byte가 넘거나 Taproot에서 BIP341이 허용하지 않는 타입이면 오류
+    legacy SIGHASH_SINGLE인데 같은 인덱스의 출력이 없어도 오류
+    이미 완성된 입력과 UTXO가 없는 입력은 건너뛴다.
+    */
+    pub fn sign(&mut self, key: &PrivateKey) -> Result<usize, PsbtError> {
+        let tx = self.unsigned_tx()?;
+        let mut signed = 0;
+        for index in 0..self.inputs.len() {
+            if self.sign_input(&tx, index, key)? {
+                signed += 1;
+            }
+        }
+        Ok(signed)
+    }
+
+    /// bip32_derivation에 master의 fingerprint로 적힌 키를 유도해서 서명한다.
+    pub fn sign_with_xpriv(&mut self, master: &ExtendedPrivateKey) -> Result<usize, PsbtError> {
+        let tx = self.unsigned_tx()?;
+        let fingerprint = master.fingerprint();
+        let mut signed = 0;
+        for index in 0..self.inputs.len() {
+            let sources: Vec<(Vec<u8>, DerivationPath)> = self.inputs[index]
+                .bip32_derivation
+                .iter()
+                .filter(|(_, source)| source.fingerprint == fingerprint)
+                .map(|(pubkey, source)| (pubkey.clone(), source.path.clone()))
+                .collect();
+            for (pubkey, path) in sources {
+                let key = master.derive_path(&path)?.private_key;
+                // fingerprint는 4bytes라 다른 키와 겹칠 수 있다.
+                if key.public_key().to_bytes() == pubkey && self.sign_input(&tx, index, &key)? {
+                    signed += 1;
+                }
+            }
+        }
+        Ok(signed)
+    }
+
+    fn sign_input(
+        &mut self,
+        tx: &Transaction,
+        index: usize,
+        key: &PrivateKey,
+    ) -> Result<bool, PsbtError> {
+        let Some(utxo) = self.spent_output(index) else {
+            return Ok(false);
+        };
+        let input = &self.inputs[index];
+        if input.is_finalized() {
+            return Ok(false);
+        }
+        if let ScriptType::Taproot(output_key) = standard::classify(&utxo.pk_script) {
+            return self.sign_taproot_input(tx, index, key, &output_key);
+        }
+
+        let pubkey = key.public_key();
+        let mut script_code = utxo.pk_script.clone();
+        if let ScriptType::ScriptHash(hash) = standard::classify(&script_code) {
+            let Some(redeem_script) = &input.redeem_script else {
+                return Ok(false);
+            };
+            if hash160(redeem_script)[..] != hash[..] {
+                return Err(PsbtError::RedeemScriptMismatch(index));
+            }
+            script_code = redeem_script.clone();
+        }
+        let sighash_type = input.sighash_type.unwrap_or(SIGHASH_ALL);
+        let sighash_byte = u8::try_from(sighash_type)
+            .map_err(|_| PsbtError::InvalidValue(PSBT_IN_SIGHASH_TYPE))?;
+        // 서명 해시 계산은 입력 인덱스가 범위를 벗어날 때만 실패한다.
+        let out_of_range = |_| PsbtError::InputIndexOutOfRange(index);
+        let legacy_sighash = |script_code: &[u8]| {
+            if sighash_type & 0x1f == SIGHASH_SINGLE && index >= tx.tx_out.len() {
+                return Err(PsbtError::InvalidValue(PSBT_IN_SIGHASH_TYPE));
+            }
+            tx.legacy_sighash(index, script_code, sighash_type)
+                .map_err(out_of_range)
+        };
+        let sighash = match standard::classify(&script_code) {
+            ScriptType::WitnessPubKeyHash(hash) => {
+                if hash != pubkey.pubkey_hash() || !pubkey.compressed {
+                    return Ok(false);
+                }
+                let script_code = standard::p2pkh(&hash);
+                tx.segwit_v0_sighash(index, &script_code, utxo.value, sighash_type)
+                    .map_err(out_of_range)?
+            }
+            ScriptType::WitnessScriptHash(hash) => {
+                let Some(witness_script) = &input.witness_script else {
+                    return Ok(false);
+                };
+                if sha256(witness_script)[..] != hash[..] {
+                    return Err(PsbtError::WitnessScriptMismatch(index));
+                }
+                if !pubkey.compressed || !has_pubkey(witness_script, &pubkey.to_bytes()) {
+                    return Ok(false);
+                }
+                tx.segwit_v0_sighash(index, witness_script, utxo.value, sighash_type)
+                    .map_err(out_of_range)?
+            }
+            ScriptType::PubKeyHash(hash) if hash == pubkey.pubkey_hash() => {
+                legacy_sighash(&script_code)?
+            }
+            ScriptType::PubKey(_) | ScriptType::Multisig { .. }
+                if has_pubkey(&script_code, &pubkey.to_bytes()) =>
+            {
+                legacy_sighash(&script_code)?
+            }
+            _ => return Ok(false),
+        };
+        let mut signature = key.sign_ecdsa(sighash);
+        signature.push(sighash_byte);
+        self.inputs[index]
+            .partial_sigs
+            .insert(pubkey.to_bytes(), signature);
+        Ok(true)
+    }
+
+    fn sign_taproot_input(
+        &mut self,
+        tx: &Transaction,
+        index: usize,
+        key: &PrivateKey,
+        output_key: &[u8; 32],
+    ) -> Result<bool, PsbtError> {
+        let input = &self.inputs[index];
+        if input.tap_internal_key != Some(key.x_only_public_key().serialize()) {
+            return Ok(false);
+        }
+        let tweaked = key.tap_tweak(input.tap_merkle_root)?;
+        if tweaked.x_only_public_key().serialize() != *output_key {
+            return Err(PsbtError::OutputKeyMismatch(index));
+        }
+        let sighash_type = match input.sighash_type.unwrap_or(SIGHASH_DEFAULT) {
+            sighash_type @ (0x00..=0x03 | 0x81..=0x83) => sighash_type as u8,
+            _ => return Err(PsbtError::InvalidValue(PSBT_IN_SIGHASH_TYPE)),
+        };
+        // Taproot 서명 해시는 모든 입력의 UTXO가 필요하다.
+        let prevouts = (0..self.inputs.len())
+            .map(|index| {
+                self.spent_output(index)
+                    .ok_or(PsbtError::MissingUtxo(index))
+            })
+            .collect::<Result<Vec<TxOut>, PsbtError>>()?;
+        let sighash = tx
+            .taproot_sighash(index, &prevouts, None, None, sighash_type)
+            .map_err(|_| PsbtError::InputIndexOutOfRange(index))?;
+        let mut signature = tweaked.sign_schnorr(sighash, &[0; 32]).to_vec();
+        if sighash_type != SIGHASH_DEFAULT as u8 {
+            signature.push(sighash_type);
+        }
+        self.inputs[index].tap_key_sig = Some(signature);
+        Ok(true)
+    }
+
+    /// Combiner: 같은 트랜잭션의 PSBT를 합친다. 양쪽에 있는 값은 이쪽 것을 남긴다.
+    pub fn combine(&mut self, other: Psbt) -> Result<(), PsbtError> {
+        if self.unsigned_tx()?.txid() != other.unsigned_tx()?.txid() {
+            return Err(PsbtError::TxMismatch);
+        }
+        merge_map(&mut self.xpubs, other.xpubs);
+        merge_map(&mut self.unknown, other.unknown);
+        for (input, other) in self.inputs.iter_mut().zip(other.inputs) {
+            input.merge(other);
+        }
+        for (output, other) in self.outputs.iter_mut().zip(other.outputs) {
+            output.merge(other);
+        }
+        Ok(())
+    }
+
+    /// Finalizer: 모든 입력을 완성한다.
+    pub fn finalize(&mut self) -> Result<(), PsbtError> {
+        for index in 0..self.inputs.len() {
+            self.finalize_input(index)?;
+        }
+        Ok(())
+    }
+
+    /*
+    입력 하나를 완성
+    부분 서명으로 final_script_sig, final_script_witness를 만들고
+    UTXO와 모르는 키를 뺀 서명 정보는 지운다. (BIP174)
+    */
+    pub fn finalize_input(&mut self, index: usize) -> Result<(), PsbtError> {
+        let input = self
+            .inputs
+            .get(index)
+            .ok_or(PsbtError::InputIndexOutOfRange(index))?;
+        if input.is_finalized() {
+            return Ok(());
+        }
+        let utxo = self
+            .spent_output(index)
+            .ok_or(PsbtError::MissingUtxo(index))?;
+        let cannot_finalize = || PsbtError::CannotFinalize(index);
+        let (script_sig, witness) = match standard::classify(&utxo.pk_script) {
+            ScriptType::Taproot(_) => {
+                let signature = input.tap_key_sig.clone().ok_or_else(cannot_finalize)?;
+                (vec![], vec![signature])
+            }
+            ScriptType::ScriptHash(_) => {
+                let redeem_script = input.redeem_script.as_ref().ok_or_else(cannot_finalize)?;
+                if witness_program(redeem_script).is_some() {
+                    let witness = input
+                        .satisfy_witness(redeem_script)
+                        .ok_or_else(cannot_finalize)?;
+                    (push_data(redeem_script), witness)
+                } else {
+                    let mut stack = input.satisfy(redeem_script).ok_or_else(cannot_finalize)?;
+                    stack.push(redeem_script.clone());
+                    (push_stack(&stack), vec![])
+                }
+            }
+            ScriptType::WitnessPubKeyHash(_) | ScriptType::WitnessScriptHash(_) => {
+                let witness = input
+                    .satisfy_witness(&utxo.pk_script)
+                    .ok_or_else(cannot_finalize)?;
+                (vec![], witness)
+            }
+            _ => {
+                let stack = input.satisfy(&utxo.pk_script).ok_or_else(cannot_finalize)?;
+                (push_stack(&stack), vec![])
+            }
+        };
+
+        let input = &mut self.inputs[index];
+        input.final_script_sig = (!script_sig.is_empty()).then_some(script_sig);
+        input.final_script_witness = (!witness.is_empty()).then_some(witness);
+        input.partial_sigs.clear();
+        input.sighash_type = None;
+        input.redeem_script = None;
+        input.witness_script = None;
+        input.bip32_derivation.clear();
+        input.tap_key_sig = None;
+        input.tap_internal_key = None;
+        input.tap_merkle_root = None;
+        Ok(())
+    }
+
+    /// Extractor: 모든 입력이 완성되어 있어야 한다.
+    pub fn extract_tx(&self) -> Result<Transaction, PsbtError> {
+        let mut tx = self.unsigned_tx()?;
+        for (index, (tx_in, input)) in tx.tx_in.iter_mut().zip(&self.inputs).enumerate() {
+            if !input.is_finalized() {
+                return Err(PsbtError::NotFinalized(index));
+            }
+            tx_in.signature_script = input.final_script_sig.clone().unwrap_or_default();
+            tx_in.script_bytes = tx_in.signature_script.len();
+            tx_in.witness = input.final_script_witness.clone().unwrap_or_default();
+        }
+        Ok(tx)
+    }
+}
+
+/// Bitcoin Core처럼 base64로 쓴다.
+impl fmt::Display for Psbt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", base64::encode(&self.serialize()))
+    }
+}
+
+impl FromStr for Psbt {
+    type Err = PsbtError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Psbt::deserialize(&base64::decode(s.trim())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::Network;
+    use crate::builder::TransactionBuilder;
+    use crate::fixtures;
+    use crate::script::verify_input_with_prevouts;
+    use crate::taproot::{TapLeaf, TapTree};
+
+    fn key(byte: u8) -> PrivateKey {
+        PrivateKey::new(&[byte; 32], Network::Regtest, true).unwrap()
+    }
+
+    #[test]
+    fn test_psbt_vectors() {
+        for vector in fixtures::PSBTS.iter().chain(fixtures::TAPROOT_PSBTS) {
+            let data = hex::decode(vector).unwrap();
+            let psbt = Psbt::deserialize(&data).unwrap();
+            assert_eq!(hex::encode(psbt.serialize()), *vector);
+        }
+        let psbt: Psbt = fixtures::PSBT_BASE64.parse().unwrap();
+        assert_eq!(psbt.to_string(), fixtures::PSBT_BASE64);
+        assert_eq!(hex::encode(psbt.serialize()), fixtures::PSBTS[0]);
+
+        let invalid: Vec<PsbtError> = fixtures::INVALID_PSBTS
+            .iter()
+            .map(|vector| Psbt::deserialize(&hex::decode(vector).unwrap()).unwrap_err())
+            .collect();
+        assert!(matches!(invalid[0], PsbtError::InvalidMagic));
+        assert!(matches!(invalid[1], PsbtError::Encoding(_)));
+        assert!(matches!(invalid[2], PsbtError::UnsignedTxHasScripts));
+        assert!(matches!(
+            invalid[3],
+            PsbtError::MissingField("PSBT_GLOBAL_UNSIGNED_TX")
+        ));
+        assert!(matches!(invalid[4], PsbtError::DuplicateKey(_)));
+        for vector in fixtures::INVALID_TAPROOT_PSBTS {
+            let data = hex::decode(vector).unwrap();
+            assert!(matches!(
+                Psbt::deserialize(&data),
+                Err(PsbtError::InvalidValue(_))
+            ));
+        }
+        assert!(matches!(
+            "cHNidP8=!".parse::<Psbt>(),
+            Err(PsbtError::InvalidBase64)
+        ));
+    }
+
+    #[test]
+    fn test_combine() {
+        let (first, second, combined) = fixtures::PSBT_COMBINE;
+        let first = Psbt::deserialize(&hex::decode(first).unwrap()).unwrap();
+        let second = Psbt::deserialize(&hex::decode(second).unwrap()).unwrap();
+        // 예제의 부분 서명은 키 순서가 아니라서 다시 직렬화한 것과 비교한다.
+        let combined = Psbt::deserialize(&hex::decode(combined).unwrap())
+            .unwrap()
+            .serialize();
+        let mut psbt = first.clone();
+        psbt.combine(second.clone()).unwrap();
+        assert_eq!(psbt.serialize(), combined);
+        let mut psbt = second;
+        psbt.combine(first).unwrap();
+        assert_eq!(psbt.serialize(), combined);
+
+        let other = Psbt::deserialize(&hex::decode(fixtures::PSBTS[1]).unwrap()).unwrap();
+        assert!(matches!(psbt.combine(other), Err(PsbtError::TxMismatch)));
+    }
+
+    /*
+    P2PKH, P2WPKH(HD 키), P2SH-P2WPKH, P2WSH 2-of-2, P2TR을 쓰는 트랜잭션을
+    두 서명자가 따로 서명하고 합쳐서 완성한다.
+    */
+    #[test]
+    fn test_roles() {
+        let (alice, bob) = (key(1), key(2));
+        let master = ExtendedPrivateKey::new_master(&[7; 32], Network::Regtest).unwrap();
+        let path: DerivationPath = "m/84h/1h/0h/0/0".parse().unwrap();
+        let hd_key = master.derive_path(&path).unwrap().private_key;
+        let hd_pubkey = hd_key.public_key();
+
+        let wrapped = standard::p2wpkh(&alice.public_key().pubkey_hash());
+        let multisig = standard::multisig(
+            2,
+            &[&alice.public_key().to_bytes(), &bob.public_key().to_bytes()],
+        )
+        .unwrap();
+        let tree = TapTree::new(vec![TapLeaf::new(vec![0x51])]).unwrap();
+        let (output_key, _) = tree.output_key(&bob.x_only_public_key());
+        let prev_tx = TransactionBuilder::new()
+            .add_input(OutPoint {
+                txid: [9; 32],
+                vout: 0,
+            })
+            .add_output(standard::p2pkh(&alice.public_key().pubkey_hash()), 10_000)
+            .add_output(standard::p2wpkh(&hd_pubkey.pubkey_hash()), 20_000)
+            .add_output(
+                standard::p2sh(&hash160(&wrapped).try_into().unwrap()),
+                30_000,
+            )
+            .add_output(
+                standard::p2wsh(&sha256(&multisig).try_into().unwrap()),
+                40_000,
+            )
+            .add_output(standard::p2tr(&output_key.serialize()), 50_000)
+            .build()
+            .unwrap();
+        let mut builder = TransactionBuilder::new().version(2);
+        for vout in 0..5 {
+            builder = builder.add_input(OutPoint {
+                txid: prev_tx.txid(),
+                vout,
+            });
+        }
+        let tx = builder
+            .add_output(standard::p2wpkh(&bob.public_key().pubkey_hash()), 140_000)
+            .build()
+            .unwrap();
+
+        let mut psbt = Psbt::from_unsigned_tx(tx.clone()).unwrap();
+        for index in 0..5 {
+            psbt.set_prev_tx(index, prev_tx.clone()).unwrap();
+        }
+        assert!(psbt.inputs[0].witness_utxo.is_none());
+        psbt.inputs[1].bip32_derivation.insert(
+            hd_pubkey.to_bytes(),
+            KeySource {
+                fingerprint: master.fingerprint(),
+                path: path.clone(),
+            },
+        );
+        psbt.inputs[2].redeem_script = Some(wrapped);
+        psbt.inputs[3].witness_script = Some(multisig);
+        psbt.inputs[4].tap_internal_key = Some(bob.x_only_public_key().serialize());
+        psbt.inputs[4].tap_merkle_root = Some(tree.merkle_root());
+        assert!(matches!(
+            psbt.finalize_input(0),
+            Err(PsbtError::CannotFinalize(0))
+        ));
+
+        // 서명할 수 없는 입력은 어느 입력이 왜 틀렸는지 알려준다.
+        let sign_err = |edit: fn(&mut Psbt), key: &PrivateKey| {
+            let mut bad = psbt.clone();
+            edit(&mut bad);
+            bad.sign(key).unwrap_err()
+        };
+        assert!(matches!(
+            sign_err(|psbt| psbt.inputs[0].sighash_type = Some(0x101), &alice),
+            PsbtError::InvalidValue(PSBT_IN_SIGHASH_TYPE)
+        ));
+        assert!(matches!(
+            sign_err(
+                |psbt| psbt.inputs[2].redeem_script = Some(vec![0x51]),
+                &alice
+            ),
+            PsbtError::RedeemScriptMismatch(2)
+        ));
+        assert!(matches!(
+            sign_err(
+                |psbt| psbt.inputs[3].witness_script = Some(vec![0x51]),
+                &bob
+            ),
+            PsbtError::WitnessScriptMismatch(3)
+        ));
+        assert!(matches!(
+            sign_err(|psbt| psbt.inputs[4].sighash_type = Some(0x04), &bob),
+            PsbtError::InvalidValue(PSBT_IN_SIGHASH_TYPE)
+        ));
+        assert!(matches!(
+            sign_err(|psbt| psbt.inputs[4].tap_merkle_root = None, &bob),
+            PsbtError::OutputKeyMismatch(4)
+        ));
+        assert!(matches!(
+            sign_err(|psbt| psbt.inputs[0].non_witness_utxo = None, &bob),
+            PsbtError::MissingUtxo(0)
+        ));
+
+        // 각자 base64로 받아서 서명한다.
+        let mut alice_psbt: Psbt = psbt.to_string().parse().unwrap();
+        assert_eq!(alice_psbt.sign(&alice).unwrap(), 3);
+        assert_eq!(alice_psbt.sign_with_xpriv(&master).unwrap(), 1);
+        let mut bob_psbt: Psbt = psbt.to_string().parse().unwrap();
+        assert_eq!(bob_psbt.sign(&bob).unwrap(), 2);
+        assert!(matches!(
+            alice_psbt.clone().finalize(),
+            Err(PsbtError::CannotFinalize(3))
+        ));
+
+        alice_psbt.combine(bob_psbt).unwrap();
+        assert_eq!(alice_psbt.inputs[3].partial_sigs.len(), 2);
+        assert!(matches!(
+            alice_psbt.extract_tx(),
+            Err(PsbtError::NotFinalized(0))
+        ));
+        alice_psbt.finalize().unwrap();
+        assert!(alice_psbt.inputs[3].witness_script.is_none());
+        let psbt: Psbt = alice_psbt.to_string().parse().unwrap();
+        assert_eq!(psbt.unsigned_tx().unwrap().txid(), tx.txid());
+        let signed = psbt.extract_tx().unwrap();
+        for index in 0..5 {
+            verify_input_with_prevouts(&signed, index, &prev_tx.tx_out).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_version_2() {
+        let data = hex::decode(fixtures::PSBTS[1]).unwrap();
+        let v0 = Psbt::deserialize(&data).unwrap();
+        let mut v2 = Psbt::deserialize(&v0.to_version(2).unwrap().serialize()).unwrap();
+        assert_eq!(v2.version, 2);
+        assert_eq!(
+            v2.unsigned_tx().unwrap().txid(),
+            v0.unsigned_tx().unwrap().txid()
+        );
+        assert_eq!(v2.to_version(0).unwrap().serialize(), data);
+        assert!(matches!(
+            v0.to_version(1),
+            Err(PsbtError::UnsupportedVersion(1))
+        ));
+
+        // 입력이 요구하는 lock time (BIP370)
+        v2.fallback_lock_time = Some(100);
+        assert_eq!(v2.lock_time().unwrap(), 100);
+        v2.inputs[0].required_height_lock_time = Some(200);
+        v2.inputs[0].required_time_lock_time = Some(LOCKTIME_THRESHOLD + 1);
+        v2.inputs[1].required_height_lock_time = Some(300);
+        assert_eq!(v2.lock_time().unwrap(), 300);
+        v2.inputs[1].required_height_lock_time = None;
+        v2.inputs[1].required_time_lock_time = Some(LOCKTIME_THRESHOLD + 2);
+        assert_eq!(v2.lock_time().unwrap(), LOCKTIME_THRESHOLD + 2);
+        v2.inputs[0].required_time_lock_time = None;
+        assert!(matches!(v2.lock_time(), Err(PsbtError::LockTimeConflict)));
+
+        // v2에는 unsigned tx를 넣을 수 없다.
+        let mut data = PSBT_MAGIC.to_vec();
+        data.extend_from_slice(&[1, PSBT_GLOBAL_UNSIGNED_TX]);
+        write_var_bytes(&mut data, &encode::serialize(&v0.unsigned_tx().unwrap()));
+        data.extend_from_slice(&[1, PSBT_GLOBAL_VERSION, 4, 2, 0, 0, 0, PSBT_SEPARATOR]);
+        assert!(matches!(
+            Psbt::deserialize(&data),
+            Err(PsbtError::FieldNotAllowed { version: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,1490 @@
+use crypto::digest::Digest;
+use crypto::ripemd160::Ripemd160;
+use crypto::sha1::Sha1;
+use secp256k1::{ecdsa, schnorr, Message, PublicKey, Secp256k1, XOnlyPublicKey};
+
+use crate::encode::{write_var_bytes, CompactSize, Encodable};
+use crate::error::ScriptError;
+use crate::locktime::{LockTime, RelativeLockTime};
+use crate::opcodes::*;
+use crate::taproot::{self, tap_leaf_hash, ControlBlock, TAPROOT_LEAF_TAPSCRIPT};
+use crate::{hash160, sha256, sha256d, Transaction, TxOut, SEQUENCE_FINAL};
+
+/*
+스크립트 인터프리터
+스크립트는 opcode와 푸시 데이터로 이루어진 스택 기반 언어
+입력의 signature_script를 먼저 실행하고 그 스택 위에서 이전 output의 pk_script를 실행해서
+마지막에 스택 맨 위가 참이면 입력을 쓸 수 있다.
+
+P2PK: <서명> | <공개키> OP_CHECKSIG
+P2PKH: <서명> <공개키> | OP_DUP OP_HASH160 <공개키 해시> OP_EQUALVERIFY OP_CHECKSIG
+P2SH: <...> <redeem 스크립트> | OP_HASH160 <스크립트 해시> OP_EQUAL, 이어서 redeem 스크립트 실행
+SegWit v0: signature_script 대신 witness로 P2WPKH, P2WSH를 검증
+Taproot(v1): witness의 Schnorr 서명 하나로 출력 키를 검증 (키 경로)
+              또는 출력 키에 약속된 tapscript를 실행 (스크립트 경로)
+
+자원 제한
+스크립트 크기 10000bytes, 푸시 하나 520bytes, opcode 201개, 스택(alt 스택 포함) 1000개
+tapscript는 스크립트 크기와 opcode 수 대신 witness 크기에 비례한 서명 검사 예산을 쓴다.
+*/
+pub const MAX_SCRIPT_SIZE: usize = 10_000;
+pub const MAX_SCRIPT_ELEMENT_SIZE: usize = 520;
+pub const MAX_OPS_PER_SCRIPT: usize = 201;
+pub const MAX_STACK_SIZE: usize = 1000;
+pub const MAX_PUBKEYS_PER_MULTISIG: usize = 20;
+
+/// witness 마지막 항목이 이 바이트로 시작하면 annex
+const ANNEX_TAG: u8 = 0x50;
+
+/// tapscript 서명 검사 예산: witness 크기 + 50, 비어 있지 않은 서명 하나마다 50
+const VALIDATION_WEIGHT_OFFSET: i64 = 50;
+const VALIDATION_WEIGHT_PER_SIGOP_PASSED: i64 = 50;
+
+/// 산술 연산에 쓰는 숫자의 최대 바이트 수
+const MAX_NUM_SIZE: usize = 4;
+/// OP_CHECKLOCKTIMEVERIFY, OP_CHECKSEQUENCEVERIFY 인자는 2^39 - 1까지
+const LOCKTIME_NUM_SIZE: usize = 5;
+
+/// 서명 해시 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigVersion {
+    /// legacy, P2SH
+    Base,
+    /// P2WPKH, P2WSH (BIP143)
+    WitnessV0,
+    /// Taproot 스크립트 경로 (BIP342)
+    Tapscript,
+}
+
+/// tapscript 실행에 필요한 값
+struct ExecutionData<'a> {
+    /// 서명 해시에 넣을 tapleaf 해시
+    leaf_hash: [u8; 32],
+    annex: Option<&'a [u8]>,
+    /// 남은 서명 검사 예산
+    validation_weight_left: i64,
+}
+
+/// 스크립트를 이루는 명령 하나
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction<'a> {
+    /// OP_0, 직접 푸시, OP_PUSHDATA1/2/4로 푸시하는 데이터
+    PushBytes(&'a [u8]),
+    Op(u8),
+}
+
+/// 스크립트를 명령 단위로 나누는 반복자, 잘린 푸시를 만나면 오류를 내고 멈춘다.
+pub struct Instructions<'a> {
+    script: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Instructions<'a> {
+    pub fn new(script: &'a [u8]) -> Instructions<'a> {
+        Instructions {
+            script,
+            position: 0,
+        }
+    }
+
+    /// 다음 명령이 시작하는 위치
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = Result<Instruction<'a>, ScriptError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.script[self.position..];
+        let (&opcode, tail) = rest.split_first()?;
+        let (len_bytes, push_len): (usize, Option<usize>) = match opcode {
+            OP_0..=OP_PUSHBYTES_75 => (0, Some(opcode as usize)),
+            OP_PUSHDATA1 => (1, tail.first().map(|&n| n as usize)),
+            OP_PUSHDATA2 => (
+                2,
+                tail.get(..2)
+                    .map(|n| u16::from_le_bytes([n[0], n[1]]) as usize),
+            ),
+            OP_PUSHDATA4 => (
+                4,
+                tail.get(..4)
+                    .map(|n| u32::from_le_bytes([n[0], n[1], n[2], n[3]]) as usize),
+            ),
+            _ => {
+                self.position += 1;
+                return Some(Ok(Instruction::Op(opcode)));
+            }
+        };
+        let data = push_len.and_then(|len| tail.get(len_bytes..len_bytes.checked_add(len)?));
+        match data {
+            Some(data) => {
+                self.position += 1 + len_bytes + data.len();
+                Some(Ok(Instruction::PushBytes(data)))
+            }
+            None => {
+                self.position = self.script.len();
+                Some(Err(ScriptError::TruncatedPush))
+            }
+        }
+    }
+}
+
+/// 데이터 푸시만으로 이루어진 스크립트인지 (OP_1NEGATE, OP_1~OP_16 포함)
+pub fn is_push_only(script: &[u8]) -> bool {
+    Instructions::new(script).all(|instruction| match instruction {
+        Ok(Instruction::PushBytes(_)) => true,
+        Ok(Instruction::Op(op)) => op <= OP_16,
+        Err(_) => false,
+    })
+}
+
+/// 데이터를 가장 짧은 푸시 명령으로 인코딩
+pub fn push_data(data: &[u8]) -> Vec<u8> {
+    let mut script = Vec::with_capacity(data.len() + 5);
+    match data.len() {
+        len @ 0..=0x4b => script.push(len as u8),
+        len @ 0x4c..=0xff => script.extend_from_slice(&[OP_PUSHDATA1, len as u8]),
+        len @ 0x100..=0xffff => {
+            script.push(OP_PUSHDATA2);
+            script.extend_from_slice(&(len as u16).to_le_bytes());
+        }
+        len => {
+            script.push(OP_PUSHDATA4);
+            script.extend_from_slice(&(len as u32).to_le_bytes());
+        }
+    }
+    script.extend_from_slice(data);
+    script
+}
+
+/*
+서명 확인
+인터프리터는 서명을 직접 확인하지 않고 이 트레이트에 맡긴다.
+트랜잭션 없이 스크립트만 실행하거나 테스트할 때는 다른 구현을 쓸 수 있다.
+*/
+pub trait SignatureChecker {
+    /// signature: DER 서명 + sighash 타입 1byte, script_code: 서명 해시에 넣을 스크립트
+    fn check_ecdsa_signature(
+        &self,
+        signature: &[u8],
+        pubkey: &[u8],
+        script_code: &[u8],
+        sig_version: SigVersion,
+    ) -> bool;
+
+    /*
+    signature: 64bytes 또는 sighash 타입이 붙은 65bytes BIP340 서명
+    pubkey: 키 경로는 x-only 출력 키, 스크립트 경로는 tapscript의 공개키
+    leaf: 스크립트 경로면 (tapleaf 해시, 마지막으로 실행한 OP_CODESEPARATOR 위치)
+    */
+    fn check_schnorr_signature(
+        &self,
+        _signature: &[u8],
+        _pubkey: &[u8; 32],
+        _annex: Option<&[u8]>,
+        _leaf: Option<([u8; 32], u32)>,
+    ) -> bool {
+        false
+    }
+
+    /// OP_CHECKLOCKTIMEVERIFY: 트랜잭션의 lock time이 lock_time을 만족하는지
+    fn check_lock_time(&self, _lock_time: LockTime) -> bool {
+        false
+    }
+
+    /// OP_CHECKSEQUENCEVERIFY: 입력의 sequence가 상대 lock time을 만족하는지
+    fn check_sequence(&self, _sequence: RelativeLockTime) -> bool {
+        false
+    }
+}
+
+/// 트랜잭션 입력의 서명 해시로 서명을 확인
+pub struct TransactionSignatureChecker<'a> {
+    tx: &'a Transaction,
+    input_index: usize,
+    /// 이전 output 금액, BIP143 서명 해시에 필요
+    value: u64,
+    /// 모든 입력의 이전 output, BIP341 서명 해시에 필요
+    prevouts: Option<&'a [TxOut]>,
+}
+
+impl<'a> TransactionSignatureChecker<'a> {
+    pub fn new(tx: &'a Transaction, input_index: usize, value: u64) -> Self {
+        TransactionSignatureChecker {
+            tx,
+            input_index,
+            value,
+            prevouts: None,
+        }
+    }
+
+    /// Taproot 입력도 확인할 수 있는 검사기
+    pub fn with_prevouts(tx: &'a Transaction, input_index: usize, prevouts: &'a [TxOut]) -> Self {
+        TransactionSignatureChecker {
+            tx,
+            input_index,
+            value: prevouts
+                .get(input_index)
+                .map_or(0, |prev_out| prev_out.value),
+            prevouts: Some(prevouts),
+        }
+    }
+}
+
+impl SignatureChecker for TransactionSignatureChecker<'_> {
+    fn check_ecdsa_signature(
+        &self,
+        signature: &[u8],
+        pubkey: &[u8],
+        script_code: &[u8],
+        sig_version: SigVersion,
+    ) -> bool {
+        let Some((&sighash_type, der)) = signature.split_last() else {
+            return false;
+        };
+        let sighash = match sig_version {
+            SigVersion::Base => {
+                self.tx
+                    .legacy_sighash(self.input_index, script_code, sighash_type as u32)
+            }
+            SigVersion::WitnessV0 => self.tx.segwit_v0_sighash(
+                self.input_index,
+                script_code,
+                self.value,
+                sighash_type as u32,
+            ),
+            SigVersion::Tapscript => return false,
+        };
+        let (Ok(sighash), Ok(pubkey), Ok(mut signature)) = (
+            sighash,
+            PublicKey::from_slice(pubkey),
+            ecdsa::Signature::from_der_lax(der),
+        ) else {
+            return false;
+        };
+        // 합의 규칙은 high-S 서명도 허용한다.
+        signature.normalize_s();
+        Secp256k1::verification_only()
+            .verify_ecdsa(&Message::from_digest(sighash), &signature, &pubkey)
+            .is_ok()
+    }
+
+    fn check_schnorr_signature(
+        &self,
+        signature: &[u8],
+        pubkey: &[u8; 32],
+        annex: Option<&[u8]>,
+        leaf: Option<([u8; 32], u32)>,
+    ) -> bool {
+        let Some(prevouts) = self.prevouts else {
+            return false;
+        };
+        let (signature, sighash_type) = match signature.len() {
+            64 => (signature, 0x00),
+            65 => (&signature[..64], signature[64]),
+            _ => return false,
+        };
+        let sighash =
+            self.tx
+                .taproot_sighash(self.input_index, prevouts, annex, leaf, sighash_type);
+        let (Ok(sighash), Ok(pubkey), Ok(signature)) = (
+            sighash,
+            XOnlyPublicKey::from_slice(pubkey),
+            schnorr::Signature::from_slice(signature),
+        ) else {
+            return false;
+        };
+        Secp256k1::verification_only()
+            .verify_schnorr(&signature, &Message::from_digest(sighash), &pubkey)
+            .is_ok()
+    }
+
+    /*
+    BIP65
+    높이와 시간을 섞어 비교할 수 없고, 입력의 sequence가 SEQUENCE_FINAL이면
+    트랜잭션의 lock time이 적용되지 않으므로 실패한다.
+    */
+    fn check_lock_time(&self, lock_time: LockTime) -> bool {
+        let Some(input) = self.tx.tx_in.get(self.input_index) else {
+            return false;
+        };
+        lock_time.is_implied_by(self.tx.absolute_lock_time()) && input.sequence != SEQUENCE_FINAL
+    }
+
+    /// BIP112, 트랜잭션 version이 2 이상이고 입력의 상대 lock time이 켜져 있어야 한다.
+    fn check_sequence(&self, sequence: RelativeLockTime) -> bool {
+        let Some(input) = self.tx.tx_in.get(self.input_index) else {
+            return false;
+        };
+        if (self.tx.version as u32) < 2 {
+            return false;
+        }
+        input
+            .relative_lock_time()
+            .is_some_and(|lock_time| sequence.is_implied_by(lock_time))
+    }
+}
+
+/*
+입력 검증
+tx의 input_index 번째 입력이 prev_out을 쓸 수 있는지 확인한다.
+Taproot 서명 해시는 모든 입력의 이전 output이 필요해서 Taproot 입력은 verify_input_with_prevouts로 검증한다.
+*/
+pub fn verify_input(
+    tx: &Transaction,
+    input_index: usize,
+    prev_out: &TxOut,
+) -> Result<(), ScriptError> {
+    let input = tx
+        .tx_in
+        .get(input_index)
+        .ok_or(ScriptError::InputIndexOutOfRange(input_index))?;
+    let checker = TransactionSignatureChecker::new(tx, input_index, prev_out.value);
+    verify_script(
+        &input.signature_script,
+        &prev_out.pk_script,
+        &input.witness,
+        &checker,
+    )
+}
+
+/// prevouts: 모든 입력이 쓰는 이전 output (입력 순서대로)
+pub fn verify_input_with_prevouts(
+    tx: &Transaction,
+    input_index: usize,
+    prevouts: &[TxOut],
+) -> Result<(), ScriptError> {
+    let (input, prev_out) = tx
+        .tx_in
+        .get(input_index)
+        .zip(prevouts.get(input_index))
+        .ok_or(ScriptError::InputIndexOutOfRange(input_index))?;
+    let checker = TransactionSignatureChecker::with_prevouts(tx, input_index, prevouts);
+    verify_script(
+        &input.signature_script,
+        &prev_out.pk_script,
+        &input.witness,
+        &checker,
+    )
+}
+
+/// signature_script, pk_script, witness를 차례로 실행해서 검증
+pub fn verify_script(
+    script_sig: &[u8],
+    script_pubkey: &[u8],
+    witness: &[Vec<u8>],
+    checker: &impl SignatureChecker,
+) -> Result<(), ScriptError> {
+    let mut stack = vec![];
+    eval_script(&mut stack, script_sig, checker, SigVersion::Base)?;
+    let p2sh_stack = stack.clone();
+    eval_script(&mut stack, script_pubkey, checker, SigVersion::Base)?;
+    if !stack.last().is_some_and(|top| cast_to_bool(top)) {
+        return Err(ScriptError::EvalFalse);
+    }
+
+    let mut had_witness = false;
+    if let Some((version, program)) = witness_program(script_pubkey) {
+        had_witness = true;
+        if !script_sig.is_empty() {
+            return Err(ScriptError::WitnessMalleated);
+        }
+        verify_witness_program(witness, version, program, false, checker)?;
+    } else if is_p2sh(script_pubkey) {
+        if !is_push_only(script_sig) {
+            return Err(ScriptError::SigPushOnly);
+        }
+        // signature_script가 푸시만 하므로 스택이 비어 있을 수 없다.
+        let mut stack = p2sh_stack;
+        let redeem_script = stack.pop().ok_or(ScriptError::EvalFalse)?;
+        eval_script(&mut stack, &redeem_script, checker, SigVersion::Base)?;
+        if !stack.last().is_some_and(|top| cast_to_bool(top)) {
+            return Err(ScriptError::EvalFalse);
+        }
+        if let Some((version, program)) = witness_program(&redeem_script) {
+            had_witness = true;
+            if script_sig != push_data(&redeem_script) {
+                return Err(ScriptError::WitnessMalleatedP2sh);
+            }
+            verify_witness_program(witness, version, program, true, checker)?;
+        }
+    }
+
+    if !had_witness && !witness.is_empty() {
+        return Err(ScriptError::WitnessUnexpected);
+    }
+    Ok(())
+}
+
+/*
+witness 프로그램 검증
+v0, 20bytes: P2WPKH, witness는 [서명, 공개키]
+v0, 32bytes: P2WSH, witness 마지막 항목이 SHA256이 프로그램과 같은 witness 스크립트
+v1, 32bytes (P2SH로 감싸지 않은 것): Taproot
+다른 버전은 나중의 소프트포크를 위해 통과시킨다.
+*/
+fn verify_witness_program(
+    witness: &[Vec<u8>],
+    version: u8,
+    program: &[u8],
+    is_p2sh: bool,
+    checker: &impl SignatureChecker,
+) -> Result<(), ScriptError> {
+    if version == 1 && program.len() == 32 && !is_p2sh {
+        return verify_taproot(witness, program.try_into().unwrap(), checker);
+    }
+    if version != 0 {
+        return Ok(());
+    }
+    let (script, mut stack) = match program.len() {
+        32 => {
+            let (script, stack) = witness
+                .split_last()
+                .ok_or(ScriptError::WitnessProgramWitnessEmpty)?;
+            if sha256(script) != program {
+                return Err(ScriptError::WitnessProgramMismatch);
+            }
+            (script.clone(), stack.to_vec())
+        }
+        20 => {
+            if witness.len() != 2 {
+                return Err(ScriptError::WitnessProgramMismatch);
+            }
+            let mut script = vec![OP_DUP, OP_HASH160];
+            script.extend_from_slice(&push_data(program));
+            script.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+            (script, witness.to_vec())
+        }
+        _ => return Err(ScriptError::WitnessProgramWrongLength),
+    };
+    if stack
+        .iter()
+        .any(|item| item.len() > MAX_SCRIPT_ELEMENT_SIZE)
+    {
+        return Err(ScriptError::PushSize);
+    }
+    eval_script(&mut stack, &script, checker, SigVersion::WitnessV0)?;
+    if stack.len() != 1 {
+        return Err(ScriptError::CleanStack);
+    }
+    if !cast_to_bool(&stack[0]) {
+        return Err(ScriptError::EvalFalse);
+    }
+    Ok(())
+}
+
+/*
+Taproot 검증 (BIP341)
+witness가 둘 이상이고 마지막 항목이 0x50으로 시작하면 annex로 떼어낸다.
+남은 항목이 하나면 키 경로: 출력 키에 대한 Schnorr 서명
+둘 이상이면 스크립트 경로: 마지막이 control block, 그 앞이 스크립트, 나머지가 스크립트의 초기 스택
+control block으로 스크립트가 출력 키에 약속되어 있는지 확인하고, 리프 버전 0xc0이면 tapscript로 실행한다.
+다른 리프 버전은 나중의 소프트포크를 위해 통과시킨다.
+*/
+fn verify_taproot(
+    witness: &[Vec<u8>],
+    output_key: &[u8; 32],
+    checker: &impl SignatureChecker,
+) -> Result<(), ScriptError> {
+    let (stack, annex) = match witness.split_last() {
+        None => return Err(ScriptError::WitnessProgramWitnessEmpty),
+        Some((last, rest)) if !rest.is_empty() && last.first() == Some(&ANNEX_TAG) => {
+            (rest, Some(last.as_slice()))
+        }
+        Some(_) => (witness, None),
+    };
+    if stack.len() != 1 {
+        let control_block = ControlBlock::from_slice(&stack[stack.len() - 1])?;
+        let script = &stack[stack.len() - 2];
+        let output_key = taproot::XOnlyPublicKey::from_slice(output_key)
+            .map_err(|_| ScriptError::WitnessProgramMismatch)?;
+        if !control_block.verify(&output_key, script) {
+            return Err(ScriptError::WitnessProgramMismatch);
+        }
+        if control_block.leaf_version != TAPROOT_LEAF_TAPSCRIPT {
+            return Ok(());
+        }
+        let mut serialized_witness = vec![];
+        CompactSize(witness.len() as u64).consensus_encode(&mut serialized_witness);
+        for item in witness {
+            write_var_bytes(&mut serialized_witness, item);
+        }
+        let mut execution = ExecutionData {
+            leaf_hash: tap_leaf_hash(script, TAPROOT_LEAF_TAPSCRIPT),
+            annex,
+            validation_weight_left: serialized_witness.len() as i64 + VALIDATION_WEIGHT_OFFSET,
+        };
+        let stack = stack[..stack.len() - 2].to_vec();
+        return verify_tapscript(stack, script, checker, &mut execution);
+    }
+    let signature = &stack[0];
+    check_schnorr_signature_encoding(signature)?;
+    if !checker.check_schnorr_signature(signature, output_key, annex, None) {
+        return Err(ScriptError::SchnorrSig);
+    }
+    Ok(())
+}
+
+/*
+tapscript 실행 (BIP342)
+스크립트를 실행하기 전에 끝까지 읽어서 OP_SUCCESSx가 하나라도 있으면 바로 통과시킨다.
+초기 스택도 1000개, 항목당 520bytes 제한을 받고, 실행 뒤 스택에는 참인 항목 하나만 남아야 한다.
+*/
+fn verify_tapscript(
+    mut stack: Vec<Vec<u8>>,
+    script: &[u8],
+    checker: &impl SignatureChecker,
+    execution: &mut ExecutionData,
+) -> Result<(), ScriptError> {
+    for instruction in Instructions::new(script) {
+        if let Instruction::Op(op) = instruction? {
+            if is_op_success(op) {
+                return Ok(());
+            }
+        }
+    }
+    check_stack_size(&stack, &[])?;
+    if stack
+        .iter()
+        .any(|item| item.len() > MAX_SCRIPT_ELEMENT_SIZE)
+    {
+        return Err(ScriptError::PushSize);
+    }
+    execute(
+        &mut stack,
+        script,
+        checker,
+        SigVersion::Tapscript,
+        Some(execution),
+    )?;
+    if stack.len() != 1 {
+        return Err(ScriptError::CleanStack);
+    }
+    if !cast_to_bool(&stack[0]) {
+        return Err(ScriptError::EvalFalse);
+    }
+    Ok(())
+}
+
+/// 64bytes, 또는 0x00이 아닌 sighash 타입이 붙은 65bytes
+fn check_schnorr_signature_encoding(signature: &[u8]) -> Result<(), ScriptError> {
+    match signature.len() {
+        64 => Ok(()),
+        65 if signature[64] != 0x00 => Ok(()),
+        65 => Err(ScriptError::SchnorrSigHashType),
+        _ => Err(ScriptError::SchnorrSigSize),
+    }
+}
+
+/// 버전 opcode(OP_0, OP_1~OP_16) + 2~40bytes 푸시 하나이면 (버전, 프로그램)
+pub fn witness_program(script: &[u8]) -> Option<(u8, &[u8])> {
+    if !(4..=42).contains(&script.len()) || script[1] as usize + 2 != script.len() {
+        return None;
+    }
+    let version = match script[0] {
+        OP_0 => 0,
+        op @ OP_1..=OP_16 => op - OP_1 + 1,
+        _ => return None,
+    };
+    Some((version, &script[2..]))
+}
+
+/// OP_HASH160 <20bytes> OP_EQUAL
+pub fn is_p2sh(script: &[u8]) -> bool {
+    script.len() == 23 && script[0] == OP_HASH160 && script[1] == 20 && script[22] == OP_EQUAL
+}
+
+/*
+스크립트 실행
+stack 위에서 script를 실행하고 결과를 stack에 남긴다.
+OP_IF 안의 실행하지 않는 분기도 끝까지 읽어서 비활성화된 opcode와 잘린 푸시를 잡는다.
+SigVersion::Tapscript로 따로 실행하면 annex가 없는 것으로 보고 서명 검사 예산을 두지 않는다.
+*/
+pub fn eval_script(
+    stack: &mut Vec<Vec<u8>>,
+    script: &[u8],
+    checker: &impl SignatureChecker,
+    sig_version: SigVersion,
+) -> Result<(), ScriptError> {
+    let mut execution = (sig_version == SigVersion::Tapscript).then(|| ExecutionData {
+        leaf_hash: tap_leaf_hash(script, TAPROOT_LEAF_TAPSCRIPT),
+        annex: None,
+        validation_weight_left: i64::MAX,
+    });
+    execute(stack, script, checker, sig_version, execution.as_mut())
+}
+
+/// execution은 SigVersion::Tapscript일 때만 있다.
+fn execute(
+    stack: &mut Vec<Vec<u8>>,
+    script: &[u8],
+    checker: &impl SignatureChecker,
+    sig_version: SigVersion,
+    mut execution: Option<&mut ExecutionData>,
+) -> Result<(), ScriptError> {
+    let is_tapscript = sig_version == SigVersion::Tapscript;
+    if script.len() > MAX_SCRIPT_SIZE && !is_tapscript {
+        return Err(ScriptError::ScriptSize);
+    }
+    let mut alt_stack: Vec<Vec<u8>> = vec![];
+    // 중첩된 OP_IF마다 그 분기를 실행하는지
+    let mut exec_stack: Vec<bool> = vec![];
+    let mut op_count = 0;
+    // 서명 해시에 넣을 스크립트는 마지막 OP_CODESEPARATOR 다음부터
+    let mut code_start = 0;
+    // tapscript 서명 해시에는 마지막 OP_CODESEPARATOR의 opcode 순번이 들어간다.
+    let mut opcode_pos = 0;
+    let mut codesep_pos = u32::MAX;
+
+    let mut instructions = Instructions::new(script);
+    while let Some(instruction) = instructions.next() {
+        opcode_pos += 1;
+        let executing = exec_stack.iter().all(|&branch| branch);
+        let op = match instruction? {
+            Instruction::PushBytes(data) => {
+                if data.len() > MAX_SCRIPT_ELEMENT_SIZE {
+                    return Err(ScriptError::PushSize);
+                }
+                if executing {
+                    stack.push(data.to_vec());
+                }
+                check_stack_size(stack, &alt_stack)?;
+                continue;
+            }
+            Instruction::Op(op) => op,
+        };
+
+        if op > OP_16 && !is_tapscript {
+            op_count += 1;
+            if op_count > MAX_OPS_PER_SCRIPT {
+                return Err(ScriptError::OpCount);
+            }
+        }
+        if DISABLED_OPCODES.contains(&op) {
+            return Err(ScriptError::DisabledOpcode(op));
+        }
+        if op == OP_VERIF || op == OP_VERNOTIF {
+            return Err(ScriptError::BadOpcode(op));
+        }
+        if !executing && !(OP_IF..=OP_ENDIF).contains(&op) {
+            continue;
+        }
+
+        match op {
+            OP_1NEGATE | OP_1..=OP_16 => {
+                let n = if op == OP_1NEGATE {
+                    -1
+                } else {
+                    (op - OP_1 + 1) as i64
+                };
+                stack.push(encode_num(n));
+            }
+
+            // 흐름 제어
+            OP_NOP | OP_NOP1 | OP_NOP4..=OP_NOP10 => {}
+            // 스택 값은 꺼내지 않는다. lock time은 5bytes까지 쓸 수 있다.
+            OP_CHECKLOCKTIMEVERIFY => {
+                let lock_time = decode_num(top(stack, 1)?, LOCKTIME_NUM_SIZE)?;
+                if lock_time < 0 {
+                    return Err(ScriptError::NegativeLockTime);
+                }
+                // u32보다 큰 lock time은 어떤 트랜잭션도 만족할 수 없다.
+                let lock_time =
+                    u32::try_from(lock_time).map_err(|_| ScriptError::UnsatisfiedLockTime)?;
+                if !checker.check_lock_time(LockTime::from_consensus(lock_time)) {
+                    return Err(ScriptError::UnsatisfiedLockTime);
+                }
+            }
+            OP_CHECKSEQUENCEVERIFY => {
+                let sequence = decode_num(top(stack, 1)?, LOCKTIME_NUM_SIZE)?;
+                if sequence < 0 {
+                    return Err(ScriptError::NegativeLockTime);
+                }
+                // 비활성화 비트가 켜져 있으면 OP_NOP
+                if let Some(sequence) = RelativeLockTime::from_sequence(sequence as u32) {
+                    if !checker.check_sequence(sequence) {
+                        return Err(ScriptError::UnsatisfiedLockTime);
+                    }
+                }
+            }
+            OP_IF | OP_NOTIF => {
+                let mut branch = false;
+                if executing {
+                    let condition = pop(stack)?;
+                    // tapscript의 OP_IF 인자는 빈 값 또는 0x01 (MINIMALIF)
+                    if is_tapscript && !matches!(condition.as_slice(), [] | [1]) {
+                        return Err(ScriptError::TapscriptMinimalIf);
+                    }
+                    let condition = cast_to_bool(&condition);
+                    branch = condition == (op == OP_IF);
+                }
+                exec_stack.push(branch);
+            }
+            OP_ELSE => {
+                let branch = exec_stack
+                    .last_mut()
+                    .ok_or(ScriptError::UnbalancedConditional)?;
+                *branch = !*branch;
+            }
+            OP_ENDIF => {
+                exec_stack.pop().ok_or(ScriptError::UnbalancedConditional)?;
+            }
+            OP_VERIFY => verify(stack, "OP_VERIFY")?,
+            OP_RETURN => return Err(ScriptError::OpReturn),
+
+            // 스택
+            OP_TOALTSTACK => alt_stack.push(pop(stack)?),
+            OP_FROMALTSTACK => {
+                let item = alt_stack
+                    .pop()
+                    .ok_or(ScriptError::InvalidAltstackOperation)?;
+                stack.push(item);
+            }
+            OP_2DROP => {
+                require(stack, 2)?;
+                stack.truncate(stack.len() - 2);
+            }
+            OP_2DUP => {
+                let len = require(stack, 2)?;
+                stack.extend_from_within(len - 2..);
+            }
+            OP_3DUP => {
+                let len = require(stack, 3)?;
+                stack.extend_from_within(len - 3..);
+            }
+            OP_2OVER => {
+                let len = require(stack, 4)?;
+                stack.extend_from_within(len - 4..len - 2);
+            }
+            OP_2ROT => {
+                let len = require(stack, 6)?;
+                let moved: Vec<_> = stack.drain(len - 6..len - 4).collect();
+                stack.extend(moved);
+            }
+            OP_2SWAP => {
+                let len = require(stack, 4)?;
+                stack[len - 4..].rotate_left(2);
+            }
+            OP_IFDUP => {
+                let top = top(stack, 1)?.clone();
+                if cast_to_bool(&top) {
+                    stack.push(top);
+                }
+            }
+            OP_DEPTH => stack.push(encode_num(stack.len() as i64)),
+            OP_DROP => {
+                pop(stack)?;
+            }
+            OP_DUP => stack.push(top(stack, 1)?.clone()),
+            OP_NIP => {
+                let len = require(stack, 2)?;
+                stack.remove(len - 2);
+            }
+            OP_OVER => stack.push(top(stack, 2)?.clone()),
+            OP_PICK | OP_ROLL => {
+                let n = pop_num(stack)?;
+                if n < 0 || n as usize >= stack.len() {
+                    return Err(ScriptError::InvalidStackOperation);
+                }
+                let index = stack.len() - 1 - n as usize;
+                let item = if op == OP_PICK {
+                    stack[index].clone()
+                } else {
+                    stack.remove(index)
+                };
+                stack.push(item);
+            }
+            OP_ROT => {
+                let len = require(stack, 3)?;
+                stack[len - 3..].rotate_left(1);
+            }
+            OP_SWAP => {
+                let len = require(stack, 2)?;
+                stack.swap(len - 2, len - 1);
+            }
+            OP_TUCK => {
+                let len = require(stack, 2)?;
+                let top = stack[len - 1].clone();
+                stack.insert(len - 2, top);
+            }
+            OP_SIZE => stack.push(encode_num(top(stack, 1)?.len() as i64)),
+
+            // 비교
+            OP_EQUAL | OP_EQUALVERIFY => {
+                let b = pop(stack)?;
+                let a = pop(stack)?;
+                stack.push(encode_bool(a == b));
+                if op == OP_EQUALVERIFY {
+                    verify(stack, "OP_EQUALVERIFY")?;
+                }
+            }
+
+            // 산술 (피연산자는 4bytes 이하)
+            OP_1ADD | OP_1SUB | OP_NEGATE | OP_ABS | OP_NOT | OP_0NOTEQUAL => {
+                let n = pop_num(stack)?;
+                let result = match op {
+                    OP_1ADD => n + 1,
+                    OP_1SUB => n - 1,
+                    OP_NEGATE => -n,
+                    OP_ABS => n.abs(),
+                    OP_NOT => (n == 0) as i64,
+                    _ => (n != 0) as i64,
+                };
+                stack.push(encode_num(result));
+            }
+            OP_ADD
+            | OP_SUB
+            | OP_BOOLAND
+            | OP_BOOLOR
+            | OP_NUMEQUAL
+            | OP_NUMEQUALVERIFY
+            | OP_NUMNOTEQUAL
+            | OP_LESSTHAN
+            | OP_GREATERTHAN
+            | OP_LESSTHANOREQUAL
+            | OP_GREATERTHANOREQUAL
+            | OP_MIN
+            | OP_MAX => {
+                let b = pop_num(stack)?;
+                let a = pop_num(stack)?;
+                let result = match op {
+                    OP_ADD => a + b,
+                    OP_SUB => a - b,
+                    OP_BOOLAND => (a != 0 && b != 0) as i64,
+                    OP_BOOLOR => (a != 0 || b != 0) as i64,
+                    OP_NUMEQUAL | OP_NUMEQUALVERIFY => (a == b) as i64,
+                    OP_NUMNOTEQUAL => (a != b) as i64,
+                    OP_LESSTHAN => (a < b) as i64,
+                    OP_GREATERTHAN => (a > b) as i64,
+                    OP_LESSTHANOREQUAL => (a <= b) as i64,
+                    OP_GREATERTHANOREQUAL => (a >= b) as i64,
+                    OP_MIN => a.min(b),
+                    _ => a.max(b),
+                };
+                stack.push(encode_num(result));
+                if op == OP_NUMEQUALVERIFY {
+                    verify(stack, "OP_NUMEQUALVERIFY")?;
+                }
+            }
+            OP_WITHIN => {
+                let max = pop_num(stack)?;
+                let min = pop_num(stack)?;
+                let x = pop_num(stack)?;
+                stack.push(encode_bool(min <= x && x < max));
+            }
+
+            // 암호
+            OP_RIPEMD160 | OP_SHA1 | OP_SHA256 | OP_HASH160 | OP_HASH256 => {
+                let data = pop(stack)?;
+                let hash = match op {
+                    OP_RIPEMD160 => ripemd160(&data),
+                    OP_SHA1 => sha1(&data),
+                    OP_SHA256 => sha256(&data),
+                    OP_HASH160 => hash160(&data),
+                    _ => sha256d(&data),
+                };
+                stack.push(hash);
+            }
+            OP_CODESEPARATOR => {
+                code_start = instructions.position();
+                codesep_pos = opcode_pos - 1;
+            }
+            OP_CHECKSIG | OP_CHECKSIGVERIFY => {
+                let pubkey = pop(stack)?;
+                let signature = pop(stack)?;
+                let valid = match execution.as_deref_mut() {
+                    Some(execution) => check_tapscript_signature(
+                        &signature,
+                        &pubkey,
+                        execution,
+                        codesep_pos,
+                        checker,
+                    )?,
+                    None => {
+                        let script_code =
+                            script_code(script, code_start, &[&signature], sig_version);
+                        checker.check_ecdsa_signature(
+                            &signature,
+                            &pubkey,
+                            &script_code,
+                            sig_version,
+                        )
+                    }
+                };
+                stack.push(encode_bool(valid));
+                if op == OP_CHECKSIGVERIFY {
+                    verify(stack, "OP_CHECKSIGVERIFY")?;
+                }
+            }
+            OP_CHECKMULTISIG | OP_CHECKMULTISIGVERIFY if is_tapscript => {
+                return Err(ScriptError::TapscriptCheckMultisig);
+            }
+            OP_CHECKMULTISIG | OP_CHECKMULTISIGVERIFY => {
+                let valid = check_multisig(
+                    stack,
+                    &mut op_count,
+                    script,
+                    code_start,
+                    checker,
+                    sig_version,
+                )?;
+                stack.push(encode_bool(valid));
+                if op == OP_CHECKMULTISIGVERIFY {
+                    verify(stack, "OP_CHECKMULTISIGVERIFY")?;
+                }
+            }
+            // <서명> <n> <공개키> -> <n + 서명이 맞으면 1>
+            OP_CHECKSIGADD => {
+                let Some(execution) = execution.as_deref_mut() else {
+                    return Err(ScriptError::BadOpcode(op));
+                };
+                let pubkey = pop(stack)?;
+                let n = pop_num(stack)?;
+                let signature = pop(stack)?;
+                let valid = check_tapscript_signature(
+                    &signature,
+                    &pubkey,
+                    execution,
+                    codesep_pos,
+                    checker,
+                )?;
+                stack.push(encode_num(n + valid as i64));
+            }
+
+            _ => return Err(ScriptError::BadOpcode(op)),
+        }
+        check_stack_size(stack, &alt_stack)?;
+    }
+
+    if !exec_stack.is_empty() {
+        return Err(ScriptError::UnbalancedConditional);
+    }
+    Ok(())
+}
+
+/*
+tapscript의 OP_CHECKSIG, OP_CHECKSIGVERIFY, OP_CHECKSIGADD (BIP342)
+빈 서명은 검사하지 않고 거짓, 비어 있지 않은 서명은 틀리면 스크립트를 실패시킨다.
+32bytes가 아닌 공개키는 나중의 소프트포크를 위한 알 수 없는 키 타입이라 서명이 있으면 참으로 본다.
+*/
+fn check_tapscript_signature(
+    signature: &[u8],
+    pubkey: &[u8],
+    execution: &mut ExecutionData,
+    codesep_pos: u32,
+    checker: &impl SignatureChecker,
+) -> Result<bool, ScriptError> {
+    if !signature.is_empty() {
+        execution.validation_weight_left -= VALIDATION_WEIGHT_PER_SIGOP_PASSED;
+        if execution.validation_weight_left < 0 {
+            return Err(ScriptError::TapscriptValidationWeight);
+        }
+    }
+    let pubkey: &[u8; 32] = match pubkey.len() {
+        0 => return Err(ScriptError::TapscriptEmptyPubkey),
+        32 => pubkey.try_into().unwrap(),
+        _ => return Ok(!signature.is_empty()),
+    };
+    if signature.is_empty() {
+        return Ok(false);
+    }
+    check_schnorr_signature_encoding(signature)?;
+    let leaf = Some((execution.leaf_hash, codesep_pos));
+    if !checker.check_schnorr_signature(signature, pubkey, execution.annex, leaf) {
+        return Err(ScriptError::SchnorrSig);
+    }
+    Ok(true)
+}
+
+/*
+OP_CHECKMULTISIG
+스택: <dummy> <서명 m개> <m> <공개키 n개> <n>
+서명은 공개키와 같은 순서로 있어야 하고, 한 번 지나간 공개키는 다시 쓰지 않는다.
+원래 구현의 버그로 하나를 더 꺼내므로 dummy가 필요하고 비어 있어야 한다. (BIP147)
+*/
+fn check_multisig(
+    stack: &mut Vec<Vec<u8>>,
+    op_count: &mut usize,
+    script: &[u8],
+    code_start: usize,
+    checker: &impl SignatureChecker,
+    sig_version: SigVersion,
+) -> Result<bool, ScriptError> {
+    let key_count = pop_num(stack)?;
+    if !(0..=MAX_PUBKEYS_PER_MULTISIG as i64).contains(&key_count) {
+        return Err(ScriptError::PubkeyCount);
+    }
+    let key_count = key_count as usize;
+    *op_count += key_count;
+    if *op_count > MAX_OPS_PER_SCRIPT {
+        return Err(ScriptError::OpCount);
+    }
+    require(stack, key_count)?;
+    let pubkeys = stack.split_off(stack.len() - key_count);
+
+    let sig_count = pop_num(stack)?;
+    if !(0..=key_count as i64).contains(&sig_count) {
+        return Err(ScriptError::SigCount);
+    }
+    let sig_count = sig_count as usize;
+    require(stack, sig_count)?;
+    let signatures = stack.split_off(stack.len() - sig_count);
+
+    let dummy = pop(stack)?;
+    if !dummy.is_empty() {
+        return Err(ScriptError::SigNullDummy);
+    }
+
+    let signature_refs: Vec<&[u8]> = signatures.iter().map(Vec::as_slice).collect();
+    let script_code = script_code(script, code_start, &signature_refs, sig_version);
+    // 스택 순서대로 맨 위(마지막) 서명과 공개키부터 맞춰본다.
+    let mut keys = pubkeys.iter().rev();
+    let mut remaining_keys = key_count;
+    for (index, signature) in signatures.iter().rev().enumerate() {
+        loop {
+            if sig_count - index > remaining_keys {
+                return Ok(false);
+            }
+            let Some(pubkey) = keys.next() else {
+                return Ok(false);
+            };
+            remaining_keys -= 1;
+            if checker.check_ecdsa_signature(signature, pubkey, &script_code, sig_version) {
+                break;
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// 서명 해시에 넣을 스크립트, legacy는 스크립트 안에 들어 있는 서명 푸시를 지운다.
+fn script_code(
+    script: &[u8],
+    code_start: usize,
+    signatures: &[&[u8]],
+    sig_version: SigVersion,
+) -> Vec<u8> {
+    let script = &script[code_start..];
+    if sig_version != SigVersion::Base {
+        return script.to_vec();
+    }
+    signatures
+        .iter()
+        .fold(script.to_vec(), |script, signature| {
+            find_and_delete(&script, &push_data(signature))
+        })
+}
+
+/// 명령 경계에서 시작하는 pattern을 모두 지움
+fn find_and_delete(script: &[u8], pattern: &[u8]) -> Vec<u8> {
+    if pattern.is_empty() {
+        return script.to_vec();
+    }
+    let mut result = Vec::with_capacity(script.len());
+    let mut instructions = Instructions::new(script);
+    let mut start = 0;
+    loop {
+        while script[start..].starts_with(pattern) {
+            start += pattern.len();
+            instructions = Instructions {
+                script,
+                position: start,
+            };
+        }
+        let Some(Ok(_)) = instructions.next() else {
+            break;
+        };
+        result.extend_from_slice(&script[start..instructions.position()]);
+        start = instructions.position();
+    }
+    result.extend_from_slice(&script[start..]);
+    result
+}
+
+fn check_stack_size(stack: &[Vec<u8>], alt_stack: &[Vec<u8>]) -> Result<(), ScriptError> {
+    if stack.len() + alt_stack.len() > MAX_STACK_SIZE {
+        return Err(ScriptError::StackSize);
+    }
+    Ok(())
+}
+
+/// 스택에 n개 이상 있는지 확인하고 스택 길이를 반환
+fn require(stack: &[Vec<u8>], n: usize) -> Result<usize, ScriptError> {
+    if stack.len() < n {
+        return Err(ScriptError::InvalidStackOperation);
+    }
+    Ok(stack.len())
+}
+
+/// 위에서 n번째 항목 (1 = 맨 위)
+fn top(stack: &[Vec<u8>], n: usize) -> Result<&Vec<u8>, ScriptError> {
+    let len = require(stack, n)?;
+    Ok(&stack[len - n])
+}
+
+fn pop(stack: &mut Vec<Vec<u8>>) -> Result<Vec<u8>, ScriptError> {
+    stack.pop().ok_or(ScriptError::InvalidStackOperation)
+}
+
+fn pop_num(stack: &mut Vec<Vec<u8>>) -> Result<i64, ScriptError> {
+    decode_num(&pop(stack)?, MAX_NUM_SIZE)
+}
+
+/// 맨 위가 참이면 꺼내고, 거짓이면 실패
+fn verify(stack: &mut Vec<Vec<u8>>, op_name: &'static str) -> Result<(), ScriptError> {
+    if !cast_to_bool(&pop(stack)?) {
+        return Err(ScriptError::VerifyFailed(op_name));
+    }
+    Ok(())
+}
+
+/// 0이 아닌 바이트가 있으면 참, 음수 0(마지막 바이트 0x80)은 거짓
+pub fn cast_to_bool(data: &[u8]) -> bool {
+    match data.split_last() {
+        None => false,
+        Some((&last, rest)) => rest.iter().any(|&byte| byte != 0) || (last & 0x7f) != 0,
+    }
+}
+
+fn encode_bool(value: bool) -> Vec<u8> {
+    if value {
+        vec![1]
+    } else {
+        vec![]
+    }
+}
+
+/*
+스크립트 숫자
+little-endian 부호-크기 표현, 마지막 바이트의 최상위 비트가 부호
+0은 빈 바이트열
+*/
+pub fn encode_num(n: i64) -> Vec<u8> {
+    let mut result = vec![];
+    let mut abs = n.unsigned_abs();
+    while abs > 0 {
+        result.push((abs & 0xff) as u8);
+        abs >>= 8;
+    }
+    if let Some(&last) = result.last() {
+        if last & 0x80 != 0 {
+            result.push(if n < 0 { 0x80 } else { 0x00 });
+        } else if n < 0 {
+            *result.last_mut().unwrap() |= 0x80;
+        }
+    }
+    result
+}
+
+pub fn decode_num(data: &[u8], max_size: usize) -> Result<i64, ScriptError> {
+    if data.len() > max_size {
+        return Err(ScriptError::NumberOverflow);
+    }
+    let Some((&last, _)) = data.split_last() else {
+        return Ok(0);
+    };
+    let mut result = data
+        .iter()
+        .rev()
+        .fold(0i64, |acc, &byte| (acc << 8) | byte as i64);
+    if last & 0x80 != 0 {
+        result &= !(0x80i64 << (8 * (data.len() - 1)));
+        result = -result;
+    }
+    Ok(result)
+}
+
+fn ripemd160(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Ripemd160::new();
+    hasher.input(data);
+    let mut hash = vec![0u8; 20];
+    hasher.result(&mut hash);
+    hash
+}
+
+fn sha1(data: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha1::new();
+    hasher.input(data);
+    let mut hash = vec![0u8; 20];
+    hasher.result(&mut hash);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, Block, TxOut};
+
+    /// 서명을 확인하지 않고 항상 같은 결과를 내는 검사기
+    struct FixedChecker(bool);
+
+    impl SignatureChecker for FixedChecker {
+        fn check_ecdsa_signature(&self, _: &[u8], _: &[u8], _: &[u8], _: SigVersion) -> bool {
+            self.0
+        }
+    }
+
+    fn run(script: &[u8]) -> Result<Vec<Vec<u8>>, ScriptError> {
+        let mut stack = vec![];
+        eval_script(&mut stack, script, &FixedChecker(true), SigVersion::Base)?;
+        Ok(stack)
+    }
+
+    fn prev_out(script: Vec<u8>, value: u64) -> TxOut {
+        TxOut {
+            value,
+            pk_script_bytes: script.len(),
+            pk_script: script,
+        }
+    }
+
+    /// P2PKH 입력의 공개키로 이전 output 스크립트를 만든다
+    fn p2pkh_for(script_sig: &[u8]) -> Vec<u8> {
+        let pushes: Vec<_> = Instructions::new(script_sig).map(Result::unwrap).collect();
+        let Instruction::PushBytes(pubkey) = pushes[1] else {
+            panic!("not a P2PKH input")
+        };
+        let mut script = vec![OP_DUP, OP_HASH160];
+        script.extend_from_slice(&push_data(&hash160(pubkey)));
+        script.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+        script
+    }
+
+    /*실제 P2PK, P2PKH, P2WPKH, P2SH-P2WPKH 입력의 서명을 검증하는지 */
+    #[test]
+    fn test_verify_real_inputs() {
+        for fixture in fixtures::SPENDS {
+            let tx = Transaction::from_hex(fixture.tx).unwrap();
+            let prev = prev_out(hex::decode(fixture.prev_script).unwrap(), fixture.value);
+            assert_eq!(
+                verify_input(&tx, fixture.input_index, &prev),
+                Ok(()),
+                "{}",
+                fixture.name
+            );
+            // 금액이 다르면 BIP143 서명이 맞지 않는다
+            if !tx.tx_in[fixture.input_index].witness.is_empty() {
+                let wrong = prev_out(prev.pk_script.clone(), fixture.value + 1);
+                assert!(verify_input(&tx, fixture.input_index, &wrong).is_err());
+            }
+        }
+
+        for fixture in &fixtures::TRANSACTIONS[2..] {
+            let mut tx = Transaction::from_hex(fixture.hex).unwrap();
+            for index in 0..tx.tx_in.len() {
+                let prev = prev_out(p2pkh_for(&tx.tx_in[index].signature_script), 0);
+                assert_eq!(verify_input(&tx, index, &prev), Ok(()), "{}", fixture.name);
+            }
+            tx.tx_out[0].value += 1;
+            let prev = prev_out(p2pkh_for(&tx.tx_in[0].signature_script), 0);
+            assert_eq!(verify_input(&tx, 0, &prev), Err(ScriptError::EvalFalse));
+        }
+    }
+
+    /*테스트넷 블록의 P2SH 2-of-2 멀티시그 입력 */
+    #[test]
+    fn test_verify_p2sh_multisig() {
+        let block = Block::from_hex(fixtures::TESTNET_SEGWIT_BLOCK).unwrap();
+        for tx in &block.txns[2..=5] {
+            let pushes: Vec<_> = Instructions::new(&tx.tx_in[0].signature_script)
+                .map(Result::unwrap)
+                .collect();
+            let Some(Instruction::PushBytes(redeem_script)) = pushes.last() else {
+                panic!("not a P2SH input")
+            };
+            let mut script = vec![OP_HASH160];
+            script.extend_from_slice(&push_data(&hash160(redeem_script)));
+            script.push(OP_EQUAL);
+            assert_eq!(verify_input(tx, 0, &prev_out(script.clone(), 0)), Ok(()));
+
+            // 서명 순서를 바꾸면 실패
+            let mut swapped = tx.clone();
+            let mut script_sig = vec![OP_0];
+            for push in [pushes[2], pushes[1], pushes[3]] {
+                let Instruction::PushBytes(data) = push else {
+                    unreachable!()
+                };
+                script_sig.extend_from_slice(&push_data(data));
+            }
+            swapped.tx_in[0].signature_script = script_sig;
+            assert_eq!(
+                verify_input(&swapped, 0, &prev_out(script, 0)),
+                Err(ScriptError::EvalFalse)
+            );
+        }
+    }
+
+    /*흐름 제어, 산술, 스택 연산 */
+    #[test]
+    fn test_eval_opcodes() {
+        // 2 3 OP_ADD 5 OP_EQUAL
+        assert_eq!(
+            run(&[0x52, 0x53, OP_ADD, 0x55, OP_EQUAL]).unwrap(),
+            vec![vec![1]]
+        );
+        // 0 OP_IF 2 OP_ELSE 3 OP_ENDIF
+        assert_eq!(
+            run(&[OP_0, OP_IF, 0x52, OP_ELSE, 0x53, OP_ENDIF]).unwrap(),
+            vec![vec![3]]
+        );
+        // 1 2 3 OP_ROT -> 2 3 1
+        assert_eq!(
+            run(&[0x51, 0x52, 0x53, OP_ROT]).unwrap(),
+            vec![vec![2], vec![3], vec![1]]
+        );
+        // -1 OP_ABS OP_1SUB OP_NOT
+        assert_eq!(
+            run(&[OP_1NEGATE, OP_ABS, OP_1SUB, OP_NOT]).unwrap(),
+            vec![vec![1]]
+        );
+        // 3 2 5 OP_WITHIN
+        assert_eq!(run(&[0x53, 0x52, 0x55, OP_WITHIN]).unwrap(), vec![vec![1]]);
+        assert_eq!(run(&[0x51, OP_SHA256, OP_SIZE]).unwrap()[1], encode_num(32));
+
+        assert_eq!(run(&[OP_RETURN]), Err(ScriptError::OpReturn));
+        assert_eq!(run(&[OP_DROP]), Err(ScriptError::InvalidStackOperation));
+        assert_eq!(run(&[0x51, OP_IF]), Err(ScriptError::UnbalancedConditional));
+        assert_eq!(run(&[OP_ENDIF]), Err(ScriptError::UnbalancedConditional));
+        assert_eq!(
+            run(&[OP_0, OP_VERIFY]),
+            Err(ScriptError::VerifyFailed("OP_VERIFY"))
+        );
+        // 실행하지 않는 분기의 비활성화된 opcode도 실패
+        assert_eq!(
+            run(&[OP_0, OP_IF, OP_CAT, OP_ENDIF]),
+            Err(ScriptError::DisabledOpcode(OP_CAT))
+        );
+        assert_eq!(
+            run(&[OP_0, OP_IF, OP_VER, OP_ENDIF]).unwrap(),
+            Vec::<Vec<u8>>::new()
+        );
+        assert_eq!(run(&[0x02, 0x01]), Err(ScriptError::TruncatedPush));
+        // 5bytes 숫자는 산술에 쓸 수 없다
+        assert_eq!(
+            run(&[0x05, 1, 2, 3, 4, 5, OP_1ADD]),
+            Err(ScriptError::NumberOverflow)
+        );
+    }
+
+    /*BIP65 OP_CHECKLOCKTIMEVERIFY, BIP112 OP_CHECKSEQUENCEVERIFY */
+    #[test]
+    fn test_lock_time_opcodes() {
+        let lock_time = |version: i32, lock_time: u32, sequence: u32, script: &[u8]| {
+            let tx = crate::builder::TransactionBuilder::new()
+                .version(version)
+                .lock_time(lock_time)
+                .add_input_with_sequence(Default::default(), sequence)
+                .add_output(vec![], 0)
+                .build()
+                .unwrap();
+            let mut stack = vec![];
+            let checker = TransactionSignatureChecker::new(&tx, 0, 0);
+            eval_script(&mut stack, script, &checker, SigVersion::Base)
+        };
+        let cltv = |n: i64| [push_data(&encode_num(n)), vec![OP_CHECKLOCKTIMEVERIFY]].concat();
+        let csv = |n: i64| [push_data(&encode_num(n)), vec![OP_CHECKSEQUENCEVERIFY]].concat();
+
+        assert_eq!(lock_time(1, 100, 0, &cltv(100)), Ok(()));
+        assert_eq!(
+            lock_time(1, 99, 0, &cltv(100)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        // 입력이 SEQUENCE_FINAL이면 lock time이 적용되지 않는다.
+        assert_eq!(
+            lock_time(1, 100, SEQUENCE_FINAL, &cltv(100)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        // 높이와 시간은 비교할 수 없다.
+        assert_eq!(
+            lock_time(1, 600_000_000, 0, &cltv(100)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        assert_eq!(lock_time(1, 600_000_000, 0, &cltv(500_000_000)), Ok(()));
+        assert_eq!(
+            lock_time(1, 100, 0, &cltv(-1)),
+            Err(ScriptError::NegativeLockTime)
+        );
+        assert_eq!(
+            lock_time(1, 100, 0, &[OP_CHECKLOCKTIMEVERIFY]),
+            Err(ScriptError::InvalidStackOperation)
+        );
+        assert_eq!(
+            lock_time(1, u32::MAX, 0, &cltv(u32::MAX as i64 + 1)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        assert_eq!(lock_time(1, u32::MAX, 0, &cltv(u32::MAX as i64)), Ok(()));
+        assert_eq!(
+            lock_time(1, 100, 0, &cltv(1 << 40)),
+            Err(ScriptError::NumberOverflow)
+        );
+
+        assert_eq!(lock_time(2, 0, 10, &csv(10)), Ok(()));
+        assert_eq!(
+            lock_time(2, 0, 9, &csv(10)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        assert_eq!(lock_time(2, 0, 0x0040_000a, &csv(0x0040_0005)), Ok(()));
+        assert_eq!(
+            lock_time(2, 0, 0x0040_000a, &csv(10)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        // version 1이거나 입력의 상대 lock time이 꺼져 있으면 실패
+        assert_eq!(
+            lock_time(1, 0, 10, &csv(10)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        assert_eq!(
+            lock_time(2, 0, SEQUENCE_FINAL, &csv(10)),
+            Err(ScriptError::UnsatisfiedLockTime)
+        );
+        // 스크립트 인자의 비활성화 비트가 켜져 있으면 OP_NOP
+        assert_eq!(lock_time(1, 0, SEQUENCE_FINAL, &csv(1 << 31)), Ok(()));
+        assert_eq!(
+            lock_time(2, 0, 10, &csv(-1)),
+            Err(ScriptError::NegativeLockTime)
+        );
+    }
+
+    /*자원 제한 */
+    #[test]
+    fn test_resource_limits() {
+        assert_eq!(
+            run(&vec![OP_NOP; MAX_OPS_PER_SCRIPT + 1]),
+            Err(ScriptError::OpCount)
+        );
+        assert!(run(&vec![OP_NOP; MAX_OPS_PER_SCRIPT]).is_ok());
+        assert_eq!(
+            run(&vec![0x51; MAX_STACK_SIZE + 1]),
+            Err(ScriptError::StackSize)
+        );
+        assert_eq!(
+            run(&vec![OP_0; MAX_SCRIPT_SIZE + 1]),
+            Err(ScriptError::ScriptSize)
+        );
+        assert_eq!(
+            run(&push_data(&[0; MAX_SCRIPT_ELEMENT_SIZE + 1])),
+            Err(ScriptError::PushSize)
+        );
+    }
+
+    /*스크립트 숫자 인코딩 */
+    #[test]
+    fn test_script_num() {
+        for (n, bytes) in [
+            (0, vec![]),
+            (1, vec![0x01]),
+            (-1, vec![0x81]),
+            (127, vec![0x7f]),
+            (128, vec![0x80, 0x00]),
+            (-128, vec![0x80, 0x80]),
+            (256, vec![0x00, 0x01]),
+        ] {
+            assert_eq!(encode_num(n), bytes);
+            assert_eq!(decode_num(&bytes, MAX_NUM_SIZE), Ok(n));
+        }
+        assert!(!cast_to_bool(&[0x00, 0x80]));
+        assert!(cast_to_bool(&[0x00, 0x81]));
+    }
+}
@@ -0,0 +1,380 @@
+use crate::encode::{self, serialize_without_witness, write_var_bytes, Encodable};
+use crate::opcodes::OP_CODESEPARATOR;
+use crate::script::{Instruction, Instructions};
+use crate::{sha256, sha256d, Transaction, TxOut};
+use anyhow::{Error, Result};
+
+/*
+서명 해시 (sighash)
+입력 하나를 서명하거나 검증할 때 서명이 약속하는 메시지 다이제스트
+sighash 타입으로 트랜잭션의 어느 부분을 서명에 포함할지 정한다.
+
+ALL: 모든 입력과 모든 출력
+NONE: 모든 입력, 출력은 포함하지 않음 (다른 입력의 sequence도 제외)
+SINGLE: 모든 입력과 같은 인덱스의 출력 하나 (다른 입력의 sequence도 제외)
+ANYONECANPAY: 위 타입에 더해서 자기 입력만 포함
+
+legacy: 트랜잭션을 고쳐서 직렬화한 뒤 sighash 타입을 붙여 double-SHA256
+BIP143(SegWit v0): 이전 output 금액을 포함하고 중간 해시를 재사용할 수 있는 방식
+BIP341(Taproot): 모든 이전 output의 금액과 스크립트를 포함하는 tagged hash 방식
+*/
+/// Taproot 전용, 서명 끝에 타입을 붙이지 않으면 ALL과 같은 의미
+pub const SIGHASH_DEFAULT: u32 = 0x00;
+pub const SIGHASH_ALL: u32 = 0x01;
+pub const SIGHASH_NONE: u32 = 0x02;
+pub const SIGHASH_SINGLE: u32 = 0x03;
+pub const SIGHASH_ANYONECANPAY: u32 = 0x80;
+
+/// legacy SIGHASH_SINGLE에서 같은 인덱스의 출력이 없을 때 서명하는 값 (1)
+const SIGHASH_SINGLE_BUG: [u8; 32] = {
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    one
+};
+
+impl Transaction {
+    /*
+    legacy 서명 해시
+    script_code: 서명하는 입력이 쓰는 이전 output의 스크립트 (P2SH는 redeem 스크립트)
+    script_code의 OP_CODESEPARATOR는 지우고 서명한다.
+    SIGHASH_SINGLE인데 같은 인덱스의 출력이 없으면 합의 규칙대로 1을 반환한다.
+    */
+    pub fn legacy_sighash(
+        &self,
+        input_index: usize,
+        script_code: &[u8],
+        sighash_type: u32,
+    ) -> Result<[u8; 32]> {
+        if input_index >= self.tx_in.len() {
+            return Err(Error::msg("Input index out of range"));
+        }
+        let base = sighash_type & 0x1f;
+        let anyone_can_pay = sighash_type & SIGHASH_ANYONECANPAY != 0;
+        if base == SIGHASH_SINGLE && input_index >= self.tx_out.len() {
+            return Ok(SIGHASH_SINGLE_BUG);
+        }
+
+        let mut tx = self.clone();
+        let script_code = remove_code_separators(script_code);
+        for (index, input) in tx.tx_in.iter_mut().enumerate() {
+            input.signature_script = if index == input_index {
+                script_code.clone()
+            } else {
+                vec![]
+            };
+            input.script_bytes = input.signature_script.len();
+            input.witness = vec![];
+            if index != input_index && (base == SIGHASH_NONE || base == SIGHASH_SINGLE) {
+                input.sequence = 0;
+            }
+        }
+        if anyone_can_pay {
+            tx.tx_in = vec![tx.tx_in[input_index].clone()];
+        }
+        match base {
+            SIGHASH_NONE => tx.tx_out.clear(),
+            SIGHASH_SINGLE => {
+                tx.tx_out.truncate(input_index + 1);
+                // 앞쪽 출력은 금액 -1, 빈 스크립트로 바꾼다.
+                for output in &mut tx.tx_out[..input_index] {
+                    *output = TxOut {
+                        value: u64::MAX,
+                        pk_script_bytes: 0,
+                        pk_script: vec![],
+                    };
+                }
+            }
+            _ => {}
+        }
+        tx.tx_in_count = tx.tx_in.len();
+        tx.tx_out_count = tx.tx_out.len();
+
+        let mut data = serialize_without_witness(&tx);
+        data.extend_from_slice(&sighash_type.to_le_bytes());
+        Ok(sha256d(&data).try_into().unwrap())
+    }
+
+    /*
+    BIP143 서명 해시 (SegWit v0)
+    script_code: P2WPKH는 해당하는 P2PKH 스크립트, P2WSH는 witness 스크립트
+    value: 서명하는 입력이 쓰는 이전 output의 금액
+    */
+    pub fn segwit_v0_sighash(
+        &self,
+        input_index: usize,
+        script_code: &[u8],
+        value: u64,
+        sighash_type: u32,
+    ) -> Result<[u8; 32]> {
+        let input = self
+            .tx_in
+            .get(input_index)
+            .ok_or(Error::msg("Input index out of range"))?;
+        let base = sighash_type & 0x1f;
+        let anyone_can_pay = sighash_type & SIGHASH_ANYONECANPAY != 0;
+        let zero = vec![0u8; 32];
+
+        let hash_prevouts = if anyone_can_pay {
+            zero.clone()
+        } else {
+            sha256d(&self.prevouts_data())
+        };
+        let hash_sequence = if anyone_can_pay || base == SIGHASH_NONE || base == SIGHASH_SINGLE {
+            zero.clone()
+        } else {
+            sha256d(&self.sequences_data())
+        };
+        let hash_outputs = match base {
+            SIGHASH_NONE => zero,
+            SIGHASH_SINGLE => match self.tx_out.get(input_index) {
+                Some(output) => sha256d(&encode::serialize(output)),
+                None => zero,
+            },
+            _ => sha256d(&self.outputs_data()),
+        };
+
+        let mut data = vec![];
+        data.extend_from_slice(&self.version.to_le_bytes());
+        data.extend_from_slice(&hash_prevouts);
+        data.extend_from_slice(&hash_sequence);
+        input.previous_output.consensus_encode(&mut data);
+        write_var_bytes(&mut data, script_code);
+        data.extend_from_slice(&value.to_le_bytes());
+        data.extend_from_slice(&input.sequence.to_le_bytes());
+        data.extend_from_slice(&hash_outputs);
+        data.extend_from_slice(&self.lock_time.to_le_bytes());
+        data.extend_from_slice(&sighash_type.to_le_bytes());
+        Ok(sha256d(&data).try_into().unwrap())
+    }
+
+    /*
+    BIP341 서명 해시 (Taproot)
+    prevouts: 모든 입력이 쓰는 이전 output (입력 순서대로)
+    annex: witness 마지막 항목이 0x50으로 시작하면 annex
+    leaf: 스크립트 경로 지출이면 (tapleaf 해시, 마지막으로 실행한 OP_CODESEPARATOR 위치)
+          OP_CODESEPARATOR가 없으면 위치는 0xffffffff
+    */
+    pub fn taproot_sighash(
+        &self,
+        input_index: usize,
+        prevouts: &[TxOut],
+        annex: Option<&[u8]>,
+        leaf: Option<([u8; 32], u32)>,
+        sighash_type: u8,
+    ) -> Result<[u8; 32]> {
+        if !matches!(sighash_type, 0x00..=0x03 | 0x81..=0x83) {
+            return Err(Error::msg("Invalid taproot sighash type"));
+        }
+        if prevouts.len() != self.tx_in.len() {
+            return Err(Error::msg("Prevouts do not match the inputs"));
+        }
+        let input = self
+            .tx_in
+            .get(input_index)
+            .ok_or(Error::msg("Input index out of range"))?;
+        let base = sighash_type as u32 & 0x03;
+        let anyone_can_pay = sighash_type as u32 & SIGHASH_ANYONECANPAY != 0;
+
+        // 0x00: sighash epoch
+        let mut data = vec![0x00, sighash_type];
+        data.extend_from_slice(&self.version.to_le_bytes());
+        data.extend_from_slice(&self.lock_time.to_le_bytes());
+        if !anyone_can_pay {
+            let mut amounts = vec![];
+            let mut scripts = vec![];
+            for prevout in prevouts {
+                amounts.extend_from_slice(&prevout.value.to_le_bytes());
+                write_var_bytes(&mut scripts, &prevout.pk_script);
+            }
+            data.extend_from_slice(&sha256(&self.prevouts_data()));
+            data.extend_from_slice(&sha256(&amounts));
+            data.extend_from_slice(&sha256(&scripts));
+            data.extend_from_slice(&sha256(&self.sequences_data()));
+        }
+        if base != SIGHASH_NONE && base != SIGHASH_SINGLE {
+            data.extend_from_slice(&sha256(&self.outputs_data()));
+        }
+
+        let spend_type = (leaf.is_some() as u8) << 1 | annex.is_some() as u8;
+        data.push(spend_type);
+        if anyone_can_pay {
+            input.previous_output.consensus_encode(&mut data);
+            let prevout = &prevouts[input_index];
+            data.extend_from_slice(&prevout.value.to_le_bytes());
+            write_var_bytes(&mut data, &prevout.pk_script);
+            data.extend_from_slice(&input.sequence.to_le_bytes());
+        } else {
+            data.extend_from_slice(&(input_index as u32).to_le_bytes());
+        }
+        if let Some(annex) = annex {
+            if annex.first() != Some(&0x50) {
+                return Err(Error::msg("Annex must start with 0x50"));
+            }
+            let mut annex_data = vec![];
+            write_var_bytes(&mut annex_data, annex);
+            data.extend_from_slice(&sha256(&annex_data));
+        }
+        if base == SIGHASH_SINGLE {
+            let output = self
+                .tx_out
+                .get(input_index)
+                .ok_or(Error::msg("SIGHASH_SINGLE without a matching output"))?;
+            data.extend_from_slice(&sha256(&encode::serialize(output)));
+        }
+        if let Some((leaf_hash, code_separator_pos)) = leaf {
+            data.extend_from_slice(&leaf_hash);
+            // key_version 0
+            data.push(0x00);
+            data.extend_from_slice(&code_separator_pos.to_le_bytes());
+        }
+        Ok(tagged_hash("TapSighash", &data))
+    }
+
+    /// 모든 입력의 outpoint를 이어붙인 데이터
+    fn prevouts_data(&self) -> Vec<u8> {
+        let mut data = vec![];
+        for input in &self.tx_in {
+            input.previous_output.consensus_encode(&mut data);
+        }
+        data
+    }
+
+    /// 모든 출력을 개수 없이 이어붙인 데이터
+    fn outputs_data(&self) -> Vec<u8> {
+        let mut data = vec![];
+        for output in &self.tx_out {
+            output.consensus_encode(&mut data);
+        }
+        data
+    }
+
+    /// 모든 입력의 sequence를 이어붙인 데이터
+    fn sequences_data(&self) -> Vec<u8> {
+        self.tx_in
+            .iter()
+            .flat_map(|input| input.sequence.to_le_bytes())
+            .collect()
+    }
+}
+
+/*
+BIP340 tagged hash
+SHA256(SHA256(tag) || SHA256(tag) || data)
+태그가 다른 곳에서 만든 해시와 섞이지 않도록 한다.
+*/
+pub fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = sha256(tag.as_bytes());
+    let mut message = tag_hash.clone();
+    message.extend_from_slice(&tag_hash);
+    message.extend_from_slice(data);
+    sha256(&message).try_into().unwrap()
+}
+
+/// 스크립트에서 OP_CODESEPARATOR를 지움 (푸시 데이터 안의 0xab는 그대로)
+fn remove_code_separators(script: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(script.len());
+    let mut instructions = Instructions::new(script);
+    let mut start = 0;
+    while let Some(Ok(instruction)) = instructions.next() {
+        if instruction != Instruction::Op(OP_CODESEPARATOR) {
+            result.extend_from_slice(&script[start..instructions.position()]);
+        }
+        start = instructions.position();
+    }
+    // 푸시가 스크립트 끝을 넘으면 남은 바이트를 그대로 둔다.
+    result.extend_from_slice(&script[start..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::deserialize_hex;
+    use crate::fixtures;
+
+    fn reversed(hex: &str) -> Vec<u8> {
+        let mut bytes = hex::decode(hex).unwrap();
+        bytes.reverse();
+        bytes
+    }
+
+    /*Bitcoin Core의 legacy sighash 벡터와 SIGHASH_SINGLE 버그 */
+    #[test]
+    fn test_legacy_sighash_vectors() {
+        for fixture in fixtures::LEGACY_SIGHASHES {
+            let tx = Transaction::from_hex(fixture.tx).unwrap();
+            let script_code = hex::decode(fixture.script_code).unwrap();
+            let sighash = tx
+                .legacy_sighash(fixture.input_index, &script_code, fixture.sighash_type)
+                .unwrap();
+            assert_eq!(sighash.to_vec(), reversed(fixture.sighash));
+        }
+
+        let mut tx = Transaction::from_hex(fixtures::LEGACY_SIGHASHES[0].tx).unwrap();
+        tx.tx_out.truncate(1);
+        assert_eq!(
+            tx.legacy_sighash(1, &[], SIGHASH_SINGLE).unwrap(),
+            SIGHASH_SINGLE_BUG
+        );
+        assert!(tx.legacy_sighash(tx.tx_in.len(), &[], SIGHASH_ALL).is_err());
+    }
+
+    /*BIP143 예제와 sighash 플래그별 벡터 */
+    #[test]
+    fn test_segwit_v0_sighash_vectors() {
+        for fixture in fixtures::SEGWIT_V0_SIGHASHES {
+            let tx = Transaction::from_hex(fixture.tx).unwrap();
+            let script_code = hex::decode(fixture.script_code).unwrap();
+            let sighash = tx
+                .segwit_v0_sighash(
+                    fixture.input_index,
+                    &script_code,
+                    fixture.value,
+                    fixture.sighash_type,
+                )
+                .unwrap();
+            assert_eq!(hex::encode(sighash), fixture.sighash);
+        }
+    }
+
+    /*BIP341 벡터 (키 경로, annex, 스크립트 경로) */
+    #[test]
+    fn test_taproot_sighash_vectors() {
+        for fixture in fixtures::TAPROOT_SIGHASHES {
+            let tx = Transaction::from_hex(fixture.tx).unwrap();
+            let prevouts: Vec<TxOut> = deserialize_hex(fixture.prevouts).unwrap();
+            let annex = fixture.annex.map(|annex| hex::decode(annex).unwrap());
+            let leaf = fixture
+                .leaf_hash
+                .map(|leaf| (hex::decode(leaf).unwrap().try_into().unwrap(), 0xffffffff));
+            let sighash = tx
+                .taproot_sighash(
+                    fixture.input_index,
+                    &prevouts,
+                    annex.as_deref(),
+                    leaf,
+                    fixture.sighash_type,
+                )
+                .unwrap();
+            assert_eq!(hex::encode(sighash), fixture.sighash);
+        }
+
+        let fixture = &fixtures::TAPROOT_SIGHASHES[0];
+        let tx = Transaction::from_hex(fixture.tx).unwrap();
+        let prevouts: Vec<TxOut> = deserialize_hex(fixture.prevouts).unwrap();
+        assert!(tx.taproot_sighash(0, &prevouts, None, None, 0x04).is_err());
+        assert!(tx.taproot_sighash(0, &[], None, None, 0x00).is_err());
+        assert!(tx
+            .taproot_sighash(0, &prevouts, Some(&[0x51]), None, 0x00)
+            .is_err());
+    }
+
+    /*푸시 데이터 안의 0xab는 지우지 않는지 */
+    #[test]
+    fn test_remove_code_separators() {
+        assert_eq!(
+            remove_code_separators(&[0xab, 0x51, 0x02, 0xab, 0xab, 0xab, 0xac]),
+            vec![0x51, 0x02, 0xab, 0xab, 0xac]
+        );
+    }
+}
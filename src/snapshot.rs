@@ -0,0 +1,187 @@
+use crate::error::BlockchainError;
+use crate::{serialize, Hash, MerkleMode, MerkleRoot, MerkleTree};
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+
+/*
+머클 트리 스냅샷
+계산해 둔 트리를 저장했다가 다시 불러오기 위한 형식
+맨 앞에 버전을 두고 그 뒤에 트리 전체를 bincode로 직렬화한다.
+불러올 때 리프 해시로 모든 레벨과 루트를 다시 계산해서
+저장된 값과 하나라도 다르면 잘못된 증명을 내주지 않도록 거부한다.
+트리 뒤에 바이트가 더 붙어 있어도 거부한다.
+
+버전
+1: nodes, levels, merkle_root (Legacy 방식만)
+2: 해시 방식(mode)과 리프 수(leaf_count) 추가
+*/
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u32,
+    tree: MerkleTree,
+}
+
+#[derive(Deserialize)]
+struct SnapshotV1 {
+    _version: u32,
+    tree: MerkleTreeV1,
+}
+
+#[derive(Deserialize)]
+struct MerkleTreeV1 {
+    nodes: Vec<Hash>,
+    levels: usize,
+    merkle_root: MerkleRoot,
+}
+
+impl MerkleTree {
+    /// 트리를 현재 버전의 스냅샷 바이트로 직렬화
+    pub fn to_snapshot(&self) -> Result<Vec<u8>, BlockchainError> {
+        serialize(&Snapshot {
+            version: SNAPSHOT_VERSION,
+            tree: self.clone(),
+        })
+    }
+
+    /// 스냅샷 바이트에서 트리를 불러오고 무결성을 확인
+    pub fn from_snapshot(bytes: &[u8]) -> Result<MerkleTree, BlockchainError> {
+        // 버전이 다르면 뒤쪽 형식도 다를 수 있으므로 버전만 먼저 읽는다.
+        let version: u32 = bincode::deserialize(bytes)?;
+        let tree = match version {
+            1 => {
+                let snapshot: SnapshotV1 = deserialize_exact(bytes)?;
+                let tree = snapshot.tree;
+                if tree.levels == 0 || tree.levels >= usize::BITS as usize {
+                    return Err(BlockchainError::CorruptedSnapshot);
+                }
+                MerkleTree {
+                    leaf_count: 1 << (tree.levels - 1),
+                    nodes: tree.nodes,
+                    levels: tree.levels,
+                    merkle_root: tree.merkle_root,
+                    mode: MerkleMode::Legacy,
+                }
+            }
+            SNAPSHOT_VERSION => deserialize_exact::<Snapshot>(bytes)?.tree,
+            _ => return Err(BlockchainError::UnsupportedSnapshotVersion(version)),
+        };
+
+        // 다시 계산하기 전에 리프를 꺼낼 수 있는지만 확인하고 나머지는 비교로 잡는다.
+        if tree.leaf_count == 0
+            || tree.nodes.len() < tree.leaf_count
+            || (tree.mode == MerkleMode::Legacy && !tree.leaf_count.is_power_of_two())
+        {
+            return Err(BlockchainError::CorruptedSnapshot);
+        }
+        let rebuilt = MerkleTree::from_leaf_hashes(tree.leaves().to_vec(), tree.mode);
+        if rebuilt.levels != tree.levels
+            || rebuilt.nodes != tree.nodes
+            || rebuilt.merkle_root != tree.merkle_root
+        {
+            return Err(BlockchainError::SnapshotRootMismatch);
+        }
+        Ok(tree)
+    }
+}
+
+/// bincode::deserialize와 같은 형식으로 읽되 뒤에 바이트가 남으면 거부
+fn deserialize_exact<'a, T: Deserialize<'a>>(bytes: &'a [u8]) -> Result<T, BlockchainError> {
+    Ok(bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+        .deserialize(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{verify_merkle_proof, Data, Proof};
+
+    fn datas() -> Vec<Data> {
+        (0..4u8).map(|i| vec![i; 4]).collect()
+    }
+
+    /*스냅샷으로 저장하고 다시 불러온 트리가 같은 증명을 내주는지 */
+    #[test]
+    fn test_snapshot_round_trip() {
+        let tree = MerkleTree::new(&datas());
+        let bytes = tree.to_snapshot().unwrap();
+        let loaded = MerkleTree::from_snapshot(&bytes).unwrap();
+        assert_eq!(loaded.nodes, tree.nodes);
+        assert_eq!(loaded.merkle_root, tree.merkle_root);
+
+        let proof = loaded.get_merkle_proof_by_index(2).unwrap();
+        let proof: Proof = bincode::deserialize(&serialize(&proof).unwrap()).unwrap();
+        assert!(verify_merkle_proof(
+            &proof,
+            &datas()[2],
+            2,
+            4,
+            &tree.root_hash(),
+            MerkleMode::Legacy
+        ));
+    }
+
+    /*망가진 스냅샷과 모르는 버전을 거부하는지 */
+    #[test]
+    fn test_snapshot_rejects_corruption() {
+        let tree = MerkleTree::new(&datas());
+        let bytes = tree.to_snapshot().unwrap();
+
+        // 저장된 리프, 중간 노드, 루트 해시의 한 바이트
+        let root = tree.nodes.len() - 1;
+        for hash in [
+            &tree.nodes[0],
+            &tree.nodes[tree.leaf_count],
+            &tree.nodes[root],
+        ] {
+            let offset = bytes.windows(hash.len()).position(|w| w == hash).unwrap();
+            let mut corrupted = bytes.clone();
+            corrupted[offset] ^= 1;
+            assert!(matches!(
+                MerkleTree::from_snapshot(&corrupted),
+                Err(BlockchainError::SnapshotRootMismatch)
+            ));
+        }
+
+        // 뒤에 덧붙인 데이터
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(matches!(
+            MerkleTree::from_snapshot(&extended),
+            Err(BlockchainError::SerializeError(_))
+        ));
+        let v1 = serialize(&(1u32, (&tree.nodes, tree.levels, &tree.merkle_root))).unwrap();
+        assert!(MerkleTree::from_snapshot(&[v1, vec![0]].concat()).is_err());
+
+        let mut future = bytes;
+        future[0] = 3;
+        assert!(matches!(
+            MerkleTree::from_snapshot(&future),
+            Err(BlockchainError::UnsupportedSnapshotVersion(3))
+        ));
+    }
+
+    /*버전 1 스냅샷도 Legacy 트리로 불러오는지 */
+    #[test]
+    fn test_snapshot_loads_version_1() {
+        let tree = MerkleTree::new(&datas());
+        let v1 = serialize(&(1u32, (&tree.nodes, tree.levels, &tree.merkle_root))).unwrap();
+        let loaded = MerkleTree::from_snapshot(&v1).unwrap();
+        assert_eq!(loaded.mode, MerkleMode::Legacy);
+        assert_eq!(loaded.num_leaves(), 4);
+        assert_eq!(loaded.nodes, tree.nodes);
+    }
+
+    /*Bitcoin 방식 트리도 저장하고 불러오는지 */
+    #[test]
+    fn test_snapshot_bitcoin_mode() {
+        let txids: Vec<Data> = (0..5u8).map(|i| vec![i; 32]).collect();
+        let tree = MerkleTree::with_mode(&txids, MerkleMode::Bitcoin);
+        let loaded = MerkleTree::from_snapshot(&tree.to_snapshot().unwrap()).unwrap();
+        assert_eq!(loaded.num_leaves(), 5);
+        assert_eq!(loaded.merkle_root, tree.merkle_root);
+    }
+}
@@ -0,0 +1,371 @@
+use anyhow::{Error, Result};
+
+use crate::opcodes::*;
+use crate::script::{push_data, witness_program, Instruction, Instructions};
+use crate::TxOut;
+
+/*
+표준 출력 스크립트
+P2PK: <공개키> OP_CHECKSIG
+P2PKH: OP_DUP OP_HASH160 <공개키 해시 20bytes> OP_EQUALVERIFY OP_CHECKSIG
+P2SH: OP_HASH160 <스크립트 해시 20bytes> OP_EQUAL
+P2WPKH: OP_0 <공개키 해시 20bytes>
+P2WSH: OP_0 <스크립트 sha256 32bytes>
+P2TR: OP_1 <x-only 공개키 32bytes>
+멀티시그: <m> <공개키 n개> <n> OP_CHECKMULTISIG (1 <= m <= n <= 16)
+NULL_DATA: OP_RETURN <데이터 푸시...>, 쓸 수 없는 output으로 데이터를 남긴다.
+크기 제한(MAX_NULL_DATA_SIZE)은 릴레이 정책이므로 null_data로 만들 때만 적용하고 판별할 때는 보지 않는다.
+*/
+
+/// 릴레이 정책상 NULL_DATA 스크립트의 최대 크기 (OP_RETURN + 푸시 opcode + 데이터 80bytes)
+pub const MAX_NULL_DATA_SIZE: usize = 83;
+
+/// 멀티시그 템플릿에 넣을 수 있는 공개키 수 (OP_1~OP_16)
+const MAX_MULTISIG_KEYS: usize = 16;
+
+/// 출력 스크립트 종류와 스크립트에 들어 있는 수신 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptType {
+    /// 압축(33bytes) 또는 비압축(65bytes) 공개키
+    PubKey(Vec<u8>),
+    PubKeyHash([u8; 20]),
+    ScriptHash([u8; 20]),
+    WitnessPubKeyHash([u8; 20]),
+    WitnessScriptHash([u8; 32]),
+    /// x-only 출력 키
+    Taproot([u8; 32]),
+    Multisig {
+        required: usize,
+        pubkeys: Vec<Vec<u8>>,
+    },
+    /// OP_RETURN 뒤에 푸시된 데이터를 이어 붙인 것
+    NullData(Vec<u8>),
+    /// 아직 정의되지 않은 버전(v1의 32bytes가 아닌 것 포함)의 witness 프로그램
+    WitnessUnknown {
+        version: u8,
+        program: Vec<u8>,
+    },
+    NonStandard,
+}
+
+impl ScriptType {
+    /// 주소로 나타낼 수 있는 수신 해시 (P2PKH, P2SH, SegWit, Taproot)
+    pub fn destination_hash(&self) -> Option<&[u8]> {
+        match self {
+            ScriptType::PubKeyHash(hash)
+            | ScriptType::ScriptHash(hash)
+            | ScriptType::WitnessPubKeyHash(hash) => Some(hash),
+            ScriptType::WitnessScriptHash(hash) | ScriptType::Taproot(hash) => Some(hash),
+            ScriptType::WitnessUnknown { program, .. } => Some(program),
+            _ => None,
+        }
+    }
+}
+
+/// 공개키 형식만 확인 (0x02/0x03 + 32bytes, 0x04 + 64bytes)
+fn is_pubkey(data: &[u8]) -> bool {
+    match data.first() {
+        Some(0x02 | 0x03) => data.len() == 33,
+        Some(0x04) => data.len() == 65,
+        _ => false,
+    }
+}
+
+pub fn p2pk(pubkey: &[u8]) -> Result<Vec<u8>> {
+    if !is_pubkey(pubkey) {
+        return Err(Error::msg("invalid public key"));
+    }
+    let mut script = push_data(pubkey);
+    script.push(OP_CHECKSIG);
+    Ok(script)
+}
+
+pub fn p2pkh(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![OP_DUP, OP_HASH160, 20];
+    script.extend_from_slice(pubkey_hash);
+    script.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+    script
+}
+
+pub fn p2sh(script_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![OP_HASH160, 20];
+    script.extend_from_slice(script_hash);
+    script.push(OP_EQUAL);
+    script
+}
+
+pub fn p2wpkh(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![OP_0, 20];
+    script.extend_from_slice(pubkey_hash);
+    script
+}
+
+pub fn p2wsh(script_hash: &[u8; 32]) -> Vec<u8> {
+    let mut script = vec![OP_0, 32];
+    script.extend_from_slice(script_hash);
+    script
+}
+
+pub fn p2tr(output_key: &[u8; 32]) -> Vec<u8> {
+    let mut script = vec![OP_1, 32];
+    script.extend_from_slice(output_key);
+    script
+}
+
+/// m-of-n 멀티시그, 공개키는 주어진 순서대로 넣는다.
+pub fn multisig(required: usize, pubkeys: &[&[u8]]) -> Result<Vec<u8>> {
+    if pubkeys.is_empty() || pubkeys.len() > MAX_MULTISIG_KEYS {
+        return Err(Error::msg("multisig needs 1 to 16 public keys"));
+    }
+    if required == 0 || required > pubkeys.len() {
+        return Err(Error::msg(
+            "required signatures must be between 1 and the number of keys",
+        ));
+    }
+    if !pubkeys.iter().all(|pubkey| is_pubkey(pubkey)) {
+        return Err(Error::msg("invalid public key"));
+    }
+    let mut script = vec![OP_1 + required as u8 - 1];
+    for pubkey in pubkeys {
+        script.extend_from_slice(&push_data(pubkey));
+    }
+    script.extend_from_slice(&[OP_1 + pubkeys.len() as u8 - 1, OP_CHECKMULTISIG]);
+    Ok(script)
+}
+
+/// OP_RETURN <data>, 릴레이 정책의 크기 제한을 넘으면 오류
+pub fn null_data(data: &[u8]) -> Result<Vec<u8>> {
+    let mut script = vec![OP_RETURN];
+    script.extend_from_slice(&push_data(data));
+    if script.len() > MAX_NULL_DATA_SIZE {
+        return Err(Error::msg("null data output exceeds 83 bytes"));
+    }
+    Ok(script)
+}
+
+/// 스크립트가 어떤 표준 템플릿인지 판별
+pub fn classify(script: &[u8]) -> ScriptType {
+    match script {
+        [OP_DUP, OP_HASH160, 20, hash @ .., OP_EQUALVERIFY, OP_CHECKSIG] if hash.len() == 20 => {
+            return ScriptType::PubKeyHash(hash.try_into().unwrap())
+        }
+        [OP_HASH160, 20, hash @ .., OP_EQUAL] if hash.len() == 20 => {
+            return ScriptType::ScriptHash(hash.try_into().unwrap())
+        }
+        [OP_RETURN, rest @ ..] => return null_data_payload(rest),
+        _ => {}
+    }
+    if let Some((version, program)) = witness_program(script) {
+        return match (version, program.len()) {
+            (0, 20) => ScriptType::WitnessPubKeyHash(program.try_into().unwrap()),
+            (0, 32) => ScriptType::WitnessScriptHash(program.try_into().unwrap()),
+            (0, _) => ScriptType::NonStandard,
+            (1, 32) => ScriptType::Taproot(program.try_into().unwrap()),
+            (version, _) => ScriptType::WitnessUnknown {
+                version,
+                program: program.to_vec(),
+            },
+        };
+    }
+    if let Some((pubkey, [OP_CHECKSIG])) = first_push(script) {
+        if is_pubkey(pubkey) {
+            return ScriptType::PubKey(pubkey.to_vec());
+        }
+    }
+    multisig_parts(script).unwrap_or(ScriptType::NonStandard)
+}
+
+/// 스크립트 맨 앞 푸시와 나머지
+fn first_push(script: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut instructions = Instructions::new(script);
+    match instructions.next()? {
+        Ok(Instruction::PushBytes(data)) => Some((data, &script[instructions.position()..])),
+        _ => None,
+    }
+}
+
+/// OP_RETURN 뒤가 푸시만으로 이루어져 있으면 (is_push_only처럼 OP_RESERVED 포함) NULL_DATA
+fn null_data_payload(rest: &[u8]) -> ScriptType {
+    let mut payload = vec![];
+    for instruction in Instructions::new(rest) {
+        match instruction {
+            Ok(Instruction::PushBytes(data)) => payload.extend_from_slice(data),
+            Ok(Instruction::Op(op)) if op <= OP_16 => {}
+            _ => return ScriptType::NonStandard,
+        }
+    }
+    ScriptType::NullData(payload)
+}
+
+/// OP_1~OP_16 small integer
+fn small_int(op: u8) -> Option<usize> {
+    (OP_1..=OP_16)
+        .contains(&op)
+        .then(|| (op - OP_1 + 1) as usize)
+}
+
+fn multisig_parts(script: &[u8]) -> Option<ScriptType> {
+    let (&last, body) = script.split_last()?;
+    if last != OP_CHECKMULTISIG {
+        return None;
+    }
+    let mut instructions = Instructions::new(body);
+    let Ok(Instruction::Op(op)) = instructions.next()? else {
+        return None;
+    };
+    let required = small_int(op)?;
+
+    let mut pubkeys = vec![];
+    let mut key_count = None;
+    for instruction in instructions {
+        match instruction.ok()? {
+            Instruction::PushBytes(data) if key_count.is_none() && is_pubkey(data) => {
+                pubkeys.push(data.to_vec())
+            }
+            Instruction::Op(op) if key_count.is_none() => key_count = Some(small_int(op)?),
+            _ => return None,
+        }
+    }
+    if key_count? != pubkeys.len() || required > pubkeys.len() {
+        return None;
+    }
+    Some(ScriptType::Multisig { required, pubkeys })
+}
+
+impl TxOut {
+    pub fn script_type(&self) -> ScriptType {
+        classify(&self.pk_script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, hash160, sha256, Block, Transaction};
+
+    const PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    /*만든 스크립트가 같은 종류로 판별되는지 */
+    #[test]
+    fn test_build_and_classify() {
+        let pubkey = hex::decode(PUBKEY).unwrap();
+        let pubkey_hash: [u8; 20] = hash160(&pubkey).try_into().unwrap();
+        let script_hash: [u8; 32] = sha256(&pubkey).try_into().unwrap();
+
+        assert_eq!(
+            classify(&p2pk(&pubkey).unwrap()),
+            ScriptType::PubKey(pubkey.clone())
+        );
+        assert_eq!(
+            classify(&p2pkh(&pubkey_hash)),
+            ScriptType::PubKeyHash(pubkey_hash)
+        );
+        assert_eq!(
+            classify(&p2sh(&pubkey_hash)),
+            ScriptType::ScriptHash(pubkey_hash)
+        );
+        assert_eq!(
+            classify(&p2wpkh(&pubkey_hash)),
+            ScriptType::WitnessPubKeyHash(pubkey_hash)
+        );
+        assert_eq!(
+            classify(&p2wsh(&script_hash)),
+            ScriptType::WitnessScriptHash(script_hash)
+        );
+        assert_eq!(
+            classify(&p2tr(&script_hash)),
+            ScriptType::Taproot(script_hash)
+        );
+        assert_eq!(
+            classify(&multisig(1, &[&pubkey, &pubkey]).unwrap()),
+            ScriptType::Multisig {
+                required: 1,
+                pubkeys: vec![pubkey.clone(), pubkey.clone()],
+            }
+        );
+        assert_eq!(
+            classify(&null_data(b"hello").unwrap()),
+            ScriptType::NullData(b"hello".to_vec())
+        );
+        assert_eq!(
+            p2wpkh(&pubkey_hash),
+            hex::decode("0014")
+                .unwrap()
+                .into_iter()
+                .chain(pubkey_hash)
+                .collect::<Vec<_>>()
+        );
+
+        assert!(p2pk(&pubkey[1..]).is_err());
+        assert!(multisig(3, &[&pubkey, &pubkey]).is_err());
+        assert!(multisig(0, &[&pubkey]).is_err());
+        assert!(null_data(&[0; 81]).is_err());
+        assert!(null_data(&[0; 80]).is_ok());
+    }
+
+    /*템플릿과 비슷하지만 표준이 아닌 스크립트 */
+    #[test]
+    fn test_classify_non_standard() {
+        let pubkey = hex::decode(PUBKEY).unwrap();
+        let mut bad_multisig = multisig(1, &[&pubkey]).unwrap();
+        // 공개키 수를 2로 바꾼다
+        let len = bad_multisig.len();
+        bad_multisig[len - 2] = OP_1 + 1;
+
+        for script in [
+            vec![],
+            vec![OP_CHECKSIG],
+            hex::decode("0013")
+                .unwrap()
+                .into_iter()
+                .chain([0; 19])
+                .collect(),
+            vec![OP_RETURN, OP_CHECKSIG],
+            [push_data(&pubkey[..32]), vec![OP_CHECKSIG]].concat(),
+            bad_multisig,
+        ] {
+            assert_eq!(
+                classify(&script),
+                ScriptType::NonStandard,
+                "{}",
+                hex::encode(&script)
+            );
+        }
+
+        assert_eq!(
+            classify(&[OP_1 + 1, 2, 0xab, 0xcd]),
+            ScriptType::WitnessUnknown {
+                version: 2,
+                program: vec![0xab, 0xcd],
+            }
+        );
+        assert_eq!(classify(&[OP_RETURN]), ScriptType::NullData(vec![]));
+        // 릴레이 정책의 크기 제한을 넘어도 NULL_DATA
+        assert_eq!(
+            classify(&[vec![OP_RETURN], push_data(&[7; 200])].concat()),
+            ScriptType::NullData(vec![7; 200])
+        );
+        assert_eq!(
+            classify(&[OP_RETURN, OP_RESERVED, OP_1]),
+            ScriptType::NullData(vec![])
+        );
+    }
+
+    /*실제 트랜잭션의 output 분류 */
+    #[test]
+    fn test_script_type_of_real_outputs() {
+        let tx = Transaction::from_hex(fixtures::TRANSACTIONS[1].hex).unwrap();
+        assert!(matches!(tx.tx_out[0].script_type(), ScriptType::PubKey(_)));
+
+        let block = Block::from_hex(fixtures::TESTNET_SEGWIT_BLOCK).unwrap();
+        let coinbase = &block.txns[0];
+        assert!(coinbase
+            .tx_out
+            .iter()
+            .any(|out| matches!(out.script_type(), ScriptType::NullData(ref data) if data.starts_with(&[0xaa, 0x21, 0xa9, 0xed]))));
+
+        let script_type = block.txns[2].tx_out[0].script_type();
+        assert_ne!(script_type, ScriptType::NonStandard);
+        assert_eq!(script_type.destination_hash().map(<[u8]>::len), Some(20));
+    }
+}
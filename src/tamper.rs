@@ -0,0 +1,207 @@
+use crate::{try_leaf_hash, Data, Hash, MerkleTree};
+
+/*
+변조 위치 찾기
+다시 계산한 루트가 BlockHeaders.merkle_root_hash와 다르면 "불일치"라는 것만 알 수 있다.
+믿을 수 있는 전체 레벨 해시(저장해 둔 MerkleTree)와 의심스러운 리프 모음을 비교해서
+어떤 리프가 바뀌었는지, 끼어들었는지, 빠졌는지를 인덱스로 알려준다.
+
+리프 해시를 순서대로 정렬 비교(LCS)하기 때문에
+중간에 리프 하나가 끼어들거나 빠져도 뒤쪽 리프가 모두 바뀐 것으로 잡히지 않는다.
+Bitcoin, Taproot 방식에서 32bytes가 아닌 의심 리프는 어떤 리프와도 같지 않은 것으로 본다.
+*/
+#[derive(Debug, Default, PartialEq)]
+pub struct TamperReport {
+    /// 내용이 바뀐 리프 (트리 기준 인덱스, 의심 리프 기준 인덱스)
+    pub altered: Vec<(usize, usize)>,
+    /// 트리에는 없는데 끼어든 리프 (의심 리프 기준 인덱스)
+    pub inserted: Vec<usize>,
+    /// 트리에는 있는데 빠진 리프 (트리 기준 인덱스)
+    pub dropped: Vec<usize>,
+    /// 리프가 모두 같은데 믿고 있던 트리의 중간 노드가 다를 때, 아래(0 = 리프)에서부터 처음으로 달라지는 레벨
+    pub first_divergent_level: Option<usize>,
+}
+
+impl TamperReport {
+    /// 변조가 하나도 없으면 true
+    pub fn is_clean(&self) -> bool {
+        self.altered.is_empty()
+            && self.inserted.is_empty()
+            && self.dropped.is_empty()
+            && self.first_divergent_level.is_none()
+    }
+}
+
+/// 리프 정렬 결과
+enum Edit {
+    Keep,
+    Drop(usize),
+    Insert(usize),
+}
+
+impl MerkleTree {
+    /*
+    의심스러운 리프 모음에서 변조된 위치를 찾음
+    리프가 모두 같은데도 위쪽 레벨이 다르면
+    믿고 있던 트리의 중간 노드가 망가진 것이므로 그 레벨을 알려준다.
+    */
+    pub fn locate_tampering(&self, suspect: &[Data]) -> TamperReport {
+        let trusted: Vec<Option<Hash>> = self.leaves().iter().cloned().map(Some).collect();
+        let suspect_hashes: Vec<Option<Hash>> = suspect
+            .iter()
+            .map(|data| try_leaf_hash(self.mode, data))
+            .collect();
+
+        let mut report = TamperReport::default();
+        let mut drops = vec![];
+        let mut inserts = vec![];
+        for edit in align(&trusted, &suspect_hashes)
+            .into_iter()
+            .chain(std::iter::once(Edit::Keep))
+        {
+            match edit {
+                Edit::Drop(i) => drops.push(i),
+                Edit::Insert(j) => inserts.push(j),
+                Edit::Keep => {
+                    // 연속된 빠짐/끼어듦은 짝을 지어 "바뀜"으로 본다.
+                    let paired = drops.len().min(inserts.len());
+                    report.altered.extend(
+                        drops
+                            .iter()
+                            .copied()
+                            .zip(inserts.iter().copied())
+                            .take(paired),
+                    );
+                    report.dropped.extend(drops.drain(..).skip(paired));
+                    report.inserted.extend(inserts.drain(..).skip(paired));
+                }
+            }
+        }
+
+        if !report.is_clean() {
+            return report;
+        }
+
+        // 리프는 모두 같으므로 의심 리프로 트리를 다시 만들어 위쪽 레벨을 비교
+        let rebuilt = MerkleTree::with_mode(suspect, self.mode);
+        report.first_divergent_level = (1..self.levels).find(|&level| {
+            (0..self.level_width(level))
+                .any(|index| self.node(level, index) != rebuilt.node(level, index))
+        });
+        report
+    }
+}
+
+/*
+두 리프 해시 목록을 최장 공통 부분 수열(LCS)로 정렬
+앞뒤의 공통 부분은 먼저 잘라내서 바뀐 구간만 표로 계산한다.
+*/
+fn align(trusted: &[Option<Hash>], suspect: &[Option<Hash>]) -> Vec<Edit> {
+    let prefix = trusted
+        .iter()
+        .zip(suspect)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = trusted[prefix..]
+        .iter()
+        .rev()
+        .zip(suspect[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &trusted[prefix..trusted.len() - suffix];
+    let b = &suspect[prefix..suspect.len() - suffix];
+
+    // lcs[i][j]: a[i..]와 b[j..]의 LCS 길이
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits: Vec<Edit> = (0..prefix).map(|_| Edit::Keep).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            edits.push(Edit::Keep);
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push(Edit::Drop(prefix + i));
+            i += 1;
+        } else {
+            edits.push(Edit::Insert(prefix + j));
+            j += 1;
+        }
+    }
+    edits.extend((0..suffix).map(|_| Edit::Keep));
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MerkleMode;
+
+    fn datas() -> Vec<Data> {
+        (0..8u8).map(|i| vec![i; 4]).collect()
+    }
+
+    /*바뀐 리프, 끼어든 리프, 빠진 리프를 찾는지 */
+    #[test]
+    fn test_locate_altered_inserted_dropped() {
+        let tree = MerkleTree::new(&datas());
+        assert!(tree.locate_tampering(&datas()).is_clean());
+
+        let mut altered = datas();
+        altered[5] = vec![99; 4];
+        let report = tree.locate_tampering(&altered);
+        assert_eq!(report.altered, vec![(5, 5)]);
+        assert_eq!(report.first_divergent_level, None);
+
+        let mut inserted = datas();
+        inserted.insert(2, vec![42; 4]);
+        let report = tree.locate_tampering(&inserted);
+        assert_eq!(report.inserted, vec![2]);
+        assert!(report.altered.is_empty() && report.dropped.is_empty());
+
+        let mut dropped = datas();
+        dropped.remove(6);
+        dropped[1] = vec![77; 4];
+        let report = tree.locate_tampering(&dropped);
+        assert_eq!(report.dropped, vec![6]);
+        assert_eq!(report.altered, vec![(1, 1)]);
+    }
+
+    /*리프는 같은데 저장된 중간 노드가 망가진 경우 그 레벨을 알려주는지 */
+    #[test]
+    fn test_locate_corrupted_inner_level() {
+        let mut tree = MerkleTree::new(&datas());
+        // 레벨 0은 리프 8개, 레벨 2의 첫 노드는 8 + 4 번째
+        tree.nodes[12] = vec![0; 4];
+        let report = tree.locate_tampering(&datas());
+        assert!(report.altered.is_empty());
+        assert_eq!(report.first_divergent_level, Some(2));
+    }
+
+    /*Bitcoin 방식에서 길이가 잘못된 txid는 패닉 없이 바뀐 리프로 잡는지 */
+    #[test]
+    fn test_locate_wrong_length_txid() {
+        let txids: Vec<Data> = (0..4u8).map(|i| vec![i; 32]).collect();
+        let tree = MerkleTree::with_mode(&txids, MerkleMode::Bitcoin);
+
+        let mut truncated = txids.clone();
+        truncated[2].pop();
+        let report = tree.locate_tampering(&truncated);
+        assert_eq!(report.altered, vec![(2, 2)]);
+        assert!(!report.is_clean());
+
+        let mut inserted = txids.clone();
+        inserted.push(vec![9; 31]);
+        assert_eq!(tree.locate_tampering(&inserted).inserted, vec![4]);
+    }
+}
@@ -0,0 +1,746 @@
+use anyhow::{Error, Result};
+use secp256k1::{schnorr, Keypair, Message, Scalar, Secp256k1};
+
+use crate::encode::write_var_bytes;
+use crate::error::{KeyError, ScriptError};
+use crate::keys::PrivateKey;
+use crate::sighash::tagged_hash;
+use crate::standard::{self, ScriptType};
+use crate::{MerkleMode, MerkleTree, Transaction, TxOut};
+
+/*
+Schnorr 서명 (BIP340)
+공개키는 y좌표가 짝수인 점으로 정하고 x좌표 32bytes만 쓴다 (x-only).
+서명은 R의 x좌표 32bytes + s 32bytes, nonce는 개인키, 메시지, aux_rand로 만든다.
+
+Taproot 키 경로 (BIP341)
+출력 키 Q = P + tG, t = TapTweak(P || 스크립트 트리 루트)
+스크립트 트리가 없으면 t = TapTweak(P)
+키 경로 지출은 Q에 대한 Schnorr 서명 하나로 끝난다.
+
+스크립트 트리 (MAST)
+리프: TapLeaf(리프 버전 || CompactSize 스크립트 길이 || 스크립트)
+가지: TapBranch(작은 해시 || 큰 해시), 정렬하므로 좌우를 기록할 필요가 없다.
+스크립트 경로 지출은 witness에 [스크립트 인자..., 스크립트, control block]을 넣는다.
+control block: (리프 버전 | 출력 키 y좌표 홀수 여부) 1byte + 내부 키 32bytes + 형제 해시 32bytes * 깊이
+*/
+
+/// tapscript 리프 버전 (BIP342)
+pub const TAPROOT_LEAF_TAPSCRIPT: u8 = 0xc0;
+/// 리프 버전을 뺀 control block 첫 바이트의 출력 키 패리티 비트
+const TAPROOT_LEAF_MASK: u8 = 0xfe;
+const TAPROOT_CONTROL_BASE_SIZE: usize = 33;
+const TAPROOT_CONTROL_NODE_SIZE: usize = 32;
+/// 스크립트 트리 최대 깊이
+pub const TAPROOT_CONTROL_MAX_NODE_COUNT: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XOnlyPublicKey(secp256k1::XOnlyPublicKey);
+
+impl XOnlyPublicKey {
+    pub fn from_slice(data: &[u8]) -> Result<Self, KeyError> {
+        secp256k1::XOnlyPublicKey::from_slice(data)
+            .map(XOnlyPublicKey)
+            .map_err(|_| KeyError::InvalidPublicKey)
+    }
+
+    pub fn serialize(&self) -> [u8; 32] {
+        self.0.serialize()
+    }
+
+    pub fn verify_schnorr(&self, message: [u8; 32], signature: &[u8]) -> bool {
+        let Ok(signature) = schnorr::Signature::from_slice(signature) else {
+            return false;
+        };
+        Secp256k1::verification_only()
+            .verify_schnorr(&signature, &Message::from_digest(message), &self.0)
+            .is_ok()
+    }
+
+    /// 내부 키를 조정한 출력 키와 출력 키 y좌표가 홀수인지
+    pub fn tap_tweak(&self, merkle_root: Option<[u8; 32]>) -> (XOnlyPublicKey, bool) {
+        let tweak = Scalar::from_be_bytes(tap_tweak_hash(self, merkle_root))
+            .expect("tweak hash is below the curve order");
+        let (output_key, parity) = self
+            .0
+            .add_tweak(&Secp256k1::verification_only(), &tweak)
+            .expect("tweaked key is not the point at infinity");
+        (XOnlyPublicKey(output_key), parity == secp256k1::Parity::Odd)
+    }
+}
+
+/// TapTweak(내부 키 || 스크립트 트리 루트)
+pub fn tap_tweak_hash(internal_key: &XOnlyPublicKey, merkle_root: Option<[u8; 32]>) -> [u8; 32] {
+    let mut data = internal_key.serialize().to_vec();
+    if let Some(root) = merkle_root {
+        data.extend_from_slice(&root);
+    }
+    tagged_hash("TapTweak", &data)
+}
+
+pub fn tap_leaf_hash(script: &[u8], leaf_version: u8) -> [u8; 32] {
+    let mut data = vec![leaf_version];
+    write_var_bytes(&mut data, script);
+    tagged_hash("TapLeaf", &data)
+}
+
+pub fn tap_branch_hash(a: &[u8], b: &[u8]) -> [u8; 32] {
+    let (small, large) = if a <= b { (a, b) } else { (b, a) };
+    tagged_hash("TapBranch", &[small, large].concat())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TapLeaf {
+    pub leaf_version: u8,
+    pub script: Vec<u8>,
+}
+
+impl TapLeaf {
+    pub fn new(script: Vec<u8>) -> TapLeaf {
+        TapLeaf {
+            leaf_version: TAPROOT_LEAF_TAPSCRIPT,
+            script,
+        }
+    }
+
+    pub fn leaf_hash(&self) -> [u8; 32] {
+        tap_leaf_hash(&self.script, self.leaf_version)
+    }
+}
+
+/*
+Taproot 스크립트 트리
+new: 리프를 주어진 순서대로 놓고 균형 트리로 묶는다. (MerkleMode::Taproot)
+     홀수 레벨의 마지막 노드는 그대로 올라간다.
+from_depths: 리프마다 깊이를 정해서 {A,{B,{C,D}}} 같은 모양도 만든다.
+             리프는 트리를 왼쪽부터 깊이 우선으로 훑은 순서로 준다. (BIP371 PSBT_OUT_TAP_TREE와 같은 방식)
+*/
+#[derive(Debug, Clone)]
+pub struct TapTree {
+    leaves: Vec<TapLeaf>,
+    /// 리프마다 리프에서 루트까지 올라가며 만나는 형제 해시
+    branches: Vec<Vec<[u8; 32]>>,
+    merkle_root: [u8; 32],
+}
+
+/// from_depths에서 아직 짝을 찾지 못한 가지, 아래에 있는 리프들의 인덱스
+struct TapNode {
+    hash: [u8; 32],
+    leaves: Vec<usize>,
+}
+
+impl TapTree {
+    pub fn new(leaves: Vec<TapLeaf>) -> Result<TapTree> {
+        check_leaves(&leaves)?;
+        let leaf_hashes: Vec<_> = leaves
+            .iter()
+            .map(|leaf| leaf.leaf_hash().to_vec())
+            .collect();
+        let tree = MerkleTree::with_mode(&leaf_hashes, MerkleMode::Taproot);
+        if tree.levels - 1 > TAPROOT_CONTROL_MAX_NODE_COUNT {
+            return Err(Error::msg("tap tree is too deep"));
+        }
+        let branches = (0..leaves.len())
+            .map(|index| {
+                let proof = tree.get_merkle_proof_by_index(index)?;
+                Ok(proof
+                    .hashes
+                    .iter()
+                    .map(|(_, hash)| hash.clone().try_into().unwrap())
+                    .collect())
+            })
+            .collect::<Result<_>>()?;
+        Ok(TapTree {
+            leaves,
+            branches,
+            merkle_root: tree.root_hash().try_into().unwrap(),
+        })
+    }
+
+    /*
+    (깊이, 리프) 목록으로 트리 만들기 (Bitcoin Core TaprootBuilder)
+    같은 깊이에 가지가 이미 있으면 둘을 묶어서 한 단계 위로 올리기를 반복한다.
+    모든 리프를 넣은 뒤 루트 하나만 남아야 완전한 트리다.
+    */
+    pub fn from_depths(leaves: Vec<(u8, TapLeaf)>) -> Result<TapTree> {
+        let (depths, leaves): (Vec<u8>, Vec<TapLeaf>) = leaves.into_iter().unzip();
+        check_leaves(&leaves)?;
+        let mut branches = vec![vec![]; leaves.len()];
+        // 깊이마다 짝을 기다리는 왼쪽 가지
+        let mut pending: Vec<Option<TapNode>> = vec![];
+        for (index, (&depth, leaf)) in depths.iter().zip(&leaves).enumerate() {
+            let mut depth = depth as usize;
+            if depth > TAPROOT_CONTROL_MAX_NODE_COUNT {
+                return Err(Error::msg("tap tree is too deep"));
+            }
+            // 이미 채워진 가지보다 얕은 곳에는 넣을 수 없다.
+            if depth + 1 < pending.len() {
+                return Err(Error::msg("tap leaf depths do not form a tree"));
+            }
+            let mut node = TapNode {
+                hash: leaf.leaf_hash(),
+                leaves: vec![index],
+            };
+            while pending.get(depth).is_some_and(Option::is_some) {
+                if depth == 0 {
+                    return Err(Error::msg("tap leaf depths do not form a tree"));
+                }
+                let left = pending.pop().flatten().unwrap();
+                for &leaf in &left.leaves {
+                    branches[leaf].push(node.hash);
+                }
+                for &leaf in &node.leaves {
+                    branches[leaf].push(left.hash);
+                }
+                node = TapNode {
+                    hash: tap_branch_hash(&left.hash, &node.hash),
+                    leaves: [left.leaves.as_slice(), &node.leaves].concat(),
+                };
+                depth -= 1;
+            }
+            if pending.len() <= depth {
+                pending.resize_with(depth + 1, || None);
+            }
+            pending[depth] = Some(node);
+        }
+        match pending.as_slice() {
+            [Some(root)] => Ok(TapTree {
+                leaves,
+                branches,
+                merkle_root: root.hash,
+            }),
+            _ => Err(Error::msg("tap leaf depths do not form a tree")),
+        }
+    }
+
+    pub fn leaves(&self) -> &[TapLeaf] {
+        &self.leaves
+    }
+
+    pub fn merkle_root(&self) -> [u8; 32] {
+        self.merkle_root
+    }
+
+    /// 내부 키와 이 트리로 만든 출력 키, y좌표가 홀수인지
+    pub fn output_key(&self, internal_key: &XOnlyPublicKey) -> (XOnlyPublicKey, bool) {
+        internal_key.tap_tweak(Some(self.merkle_root()))
+    }
+
+    /// leaf_index 번째 리프를 스크립트 경로로 쓸 때 witness 마지막에 넣을 control block
+    pub fn control_block(
+        &self,
+        internal_key: &XOnlyPublicKey,
+        leaf_index: usize,
+    ) -> Result<ControlBlock> {
+        let leaf = self
+            .leaves
+            .get(leaf_index)
+            .ok_or_else(|| Error::msg("leaf index out of range"))?;
+        let (_, output_key_parity) = self.output_key(internal_key);
+        Ok(ControlBlock {
+            leaf_version: leaf.leaf_version,
+            output_key_parity,
+            internal_key: *internal_key,
+            merkle_branch: self.branches[leaf_index].clone(),
+        })
+    }
+}
+
+fn check_leaves(leaves: &[TapLeaf]) -> Result<()> {
+    if leaves.is_empty() {
+        return Err(Error::msg("tap tree needs at least one leaf"));
+    }
+    // 패리티 비트 자리를 쓰는 홀수 버전과 annex 태그(0x50)는 리프 버전이 될 수 없다.
+    if leaves
+        .iter()
+        .any(|leaf| leaf.leaf_version & !TAPROOT_LEAF_MASK != 0 || leaf.leaf_version == 0x50)
+    {
+        return Err(Error::msg("invalid tap leaf version"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlBlock {
+    pub leaf_version: u8,
+    /// 출력 키의 y좌표가 홀수인지
+    pub output_key_parity: bool,
+    pub internal_key: XOnlyPublicKey,
+    /// 리프에서 루트까지 올라가며 만나는 형제 해시
+    pub merkle_branch: Vec<[u8; 32]>,
+}
+
+impl ControlBlock {
+    pub fn from_slice(data: &[u8]) -> Result<ControlBlock, ScriptError> {
+        if data.len() < TAPROOT_CONTROL_BASE_SIZE
+            || !(data.len() - TAPROOT_CONTROL_BASE_SIZE).is_multiple_of(TAPROOT_CONTROL_NODE_SIZE)
+            || (data.len() - TAPROOT_CONTROL_BASE_SIZE) / TAPROOT_CONTROL_NODE_SIZE
+                > TAPROOT_CONTROL_MAX_NODE_COUNT
+        {
+            return Err(ScriptError::TaprootControlBlockSize);
+        }
+        let internal_key = XOnlyPublicKey::from_slice(&data[1..TAPROOT_CONTROL_BASE_SIZE])
+            .map_err(|_| ScriptError::WitnessProgramMismatch)?;
+        Ok(ControlBlock {
+            leaf_version: data[0] & TAPROOT_LEAF_MASK,
+            output_key_parity: data[0] & 1 == 1,
+            internal_key,
+            merkle_branch: data[TAPROOT_CONTROL_BASE_SIZE..]
+                .chunks(TAPROOT_CONTROL_NODE_SIZE)
+                .map(|node| node.try_into().unwrap())
+                .collect(),
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut data = vec![self.leaf_version | self.output_key_parity as u8];
+        data.extend_from_slice(&self.internal_key.serialize());
+        for node in &self.merkle_branch {
+            data.extend_from_slice(node);
+        }
+        data
+    }
+
+    /// 스크립트가 이 control block의 경로로 출력 키에 약속되어 있는지
+    pub fn verify(&self, output_key: &XOnlyPublicKey, script: &[u8]) -> bool {
+        let root = self
+            .merkle_branch
+            .iter()
+            .fold(tap_leaf_hash(script, self.leaf_version), |hash, node| {
+                tap_branch_hash(&hash, node)
+            });
+        let (tweaked, parity) = self.internal_key.tap_tweak(Some(root));
+        tweaked == *output_key && parity == self.output_key_parity
+    }
+}
+
+impl PrivateKey {
+    fn keypair(&self) -> Keypair {
+        Keypair::from_seckey_slice(&Secp256k1::signing_only(), &self.secret_bytes())
+            .expect("private key is always a valid secret")
+    }
+
+    pub fn x_only_public_key(&self) -> XOnlyPublicKey {
+        XOnlyPublicKey(self.keypair().x_only_public_key().0)
+    }
+
+    pub fn sign_schnorr(&self, message: [u8; 32], aux_rand: &[u8; 32]) -> [u8; 64] {
+        Secp256k1::signing_only()
+            .sign_schnorr_with_aux_rand(&Message::from_digest(message), &self.keypair(), aux_rand)
+            .serialize()
+    }
+
+    /// 출력 키에 대응하는 개인키, 내부 키의 y좌표가 홀수면 먼저 부호를 바꾼다.
+    pub fn tap_tweak(&self, merkle_root: Option<[u8; 32]>) -> Result<PrivateKey, KeyError> {
+        let tweak = Scalar::from_be_bytes(tap_tweak_hash(&self.x_only_public_key(), merkle_root))
+            .map_err(|_| KeyError::InvalidSecretKey)?;
+        let tweaked = self
+            .keypair()
+            .add_xonly_tweak(&Secp256k1::verification_only(), &tweak)
+            .map_err(|_| KeyError::InvalidSecretKey)?;
+        PrivateKey::new(&tweaked.secret_bytes(), self.network, true)
+    }
+}
+
+impl Transaction {
+    /*
+    Taproot 키 경로 서명
+    key: 내부 개인키, merkle_root: 출력 키를 만들 때 쓴 스크립트 트리 루트
+    witness에는 서명 하나만 넣고, SIGHASH_DEFAULT(0x00)가 아니면 서명 뒤에 sighash 타입을 붙인다.
+    aux_rand는 0으로 써서 같은 입력에는 항상 같은 서명이 나온다.
+    */
+    pub fn sign_taproot_key_spend(
+        &mut self,
+        input_index: usize,
+        prevouts: &[TxOut],
+        key: &PrivateKey,
+        merkle_root: Option<[u8; 32]>,
+        sighash_type: u8,
+    ) -> Result<()> {
+        let prev_out = prevouts
+            .get(input_index)
+            .ok_or_else(|| Error::msg("input index out of range"))?;
+        let ScriptType::Taproot(output_key) = standard::classify(&prev_out.pk_script) else {
+            return Err(Error::msg(
+                "only P2TR outputs can be signed with a key spend",
+            ));
+        };
+        let tweaked = key.tap_tweak(merkle_root)?;
+        if tweaked.x_only_public_key().serialize() != output_key {
+            return Err(Error::msg("key does not match the output key"));
+        }
+
+        let sighash = self.taproot_sighash(input_index, prevouts, None, None, sighash_type)?;
+        let mut signature = tweaked.sign_schnorr(sighash, &[0; 32]).to_vec();
+        if sighash_type != 0x00 {
+            signature.push(sighash_type);
+        }
+        let input = &mut self.tx_in[input_index];
+        input.signature_script.clear();
+        input.script_bytes = 0;
+        input.witness = vec![signature];
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::{Address, Network};
+    use crate::encode::deserialize_hex;
+    use crate::fixtures;
+    use crate::script::{verify_input, verify_input_with_prevouts};
+
+    fn array(data: &str) -> [u8; 32] {
+        hex::decode(data).unwrap().try_into().unwrap()
+    }
+
+    /*BIP340 예제 서명, 검증 */
+    #[test]
+    fn test_schnorr_vectors() {
+        for fixture in fixtures::SCHNORR_SIGNATURES {
+            let message = array(fixture.message);
+            let signature = hex::decode(fixture.signature).unwrap();
+            if let (Some(secret), Some(aux_rand)) = (fixture.secret, fixture.aux_rand) {
+                let key = PrivateKey::new(&array(secret), Network::Bitcoin, true).unwrap();
+                assert_eq!(
+                    hex::encode(key.x_only_public_key().serialize()),
+                    fixture.pubkey
+                );
+                assert_eq!(
+                    key.sign_schnorr(message, &array(aux_rand)).to_vec(),
+                    signature
+                );
+            }
+            let valid = XOnlyPublicKey::from_slice(&hex::decode(fixture.pubkey).unwrap())
+                .is_ok_and(|pubkey| pubkey.verify_schnorr(message, &signature));
+            assert_eq!(valid, fixture.valid, "{}", fixture.signature);
+        }
+    }
+
+    /*BIP341 키 경로 예제를 서명하고 검증 */
+    #[test]
+    fn test_taproot_key_spends() {
+        let mut tx = Transaction::from_hex(fixtures::TAPROOT_KEY_SPEND_TX).unwrap();
+        let prevouts: Vec<TxOut> = deserialize_hex(fixtures::TAPROOT_KEY_SPEND_PREVOUTS).unwrap();
+        for fixture in fixtures::TAPROOT_KEY_SPENDS {
+            let key =
+                PrivateKey::new(&array(fixture.internal_secret), Network::Bitcoin, true).unwrap();
+            let merkle_root = fixture.merkle_root.map(array);
+            let tweaked = key.tap_tweak(merkle_root).unwrap();
+            assert_eq!(hex::encode(tweaked.secret_bytes()), fixture.tweaked_secret);
+
+            let (output_key, _) = key.x_only_public_key().tap_tweak(merkle_root);
+            assert_eq!(
+                standard::classify(&prevouts[fixture.input_index].pk_script),
+                ScriptType::Taproot(output_key.serialize())
+            );
+
+            tx.sign_taproot_key_spend(
+                fixture.input_index,
+                &prevouts,
+                &key,
+                merkle_root,
+                fixture.sighash_type,
+            )
+            .unwrap();
+            assert_eq!(
+                hex::encode(&tx.tx_in[fixture.input_index].witness[0]),
+                fixture.signature
+            );
+            assert_eq!(
+                verify_input_with_prevouts(&tx, fixture.input_index, &prevouts),
+                Ok(())
+            );
+            // 이전 output 목록이 없으면 서명 해시를 만들 수 없다.
+            assert_eq!(
+                verify_input(&tx, fixture.input_index, &prevouts[fixture.input_index]),
+                Err(ScriptError::SchnorrSig)
+            );
+
+            // 잘못된 스크립트 트리 루트
+            assert!(tx
+                .sign_taproot_key_spend(
+                    fixture.input_index,
+                    &prevouts,
+                    &key,
+                    Some([0; 32]),
+                    fixture.sighash_type
+                )
+                .is_err());
+        }
+    }
+
+    /*BIP341 스크립트 트리 예제로 리프 해시, 루트, 출력 키, control block 확인 */
+    #[test]
+    fn test_tap_trees() {
+        for fixture in fixtures::TAP_TREES {
+            let internal_key =
+                XOnlyPublicKey::from_slice(&hex::decode(fixture.internal_key).unwrap()).unwrap();
+            let merkle_root = if fixture.leaves.is_empty() {
+                None
+            } else {
+                let tree = TapTree::from_depths(
+                    fixture
+                        .leaves
+                        .iter()
+                        .map(|leaf| {
+                            let script = hex::decode(leaf.script).unwrap();
+                            (
+                                leaf.depth,
+                                TapLeaf {
+                                    leaf_version: leaf.leaf_version,
+                                    script,
+                                },
+                            )
+                        })
+                        .collect(),
+                )
+                .unwrap();
+                for (index, leaf) in fixture.leaves.iter().enumerate() {
+                    assert_eq!(
+                        hex::encode(tree.leaves()[index].leaf_hash()),
+                        leaf.leaf_hash
+                    );
+                    let control_block = tree.control_block(&internal_key, index).unwrap();
+                    assert_eq!(hex::encode(control_block.serialize()), leaf.control_block);
+                    assert_eq!(
+                        ControlBlock::from_slice(&control_block.serialize()),
+                        Ok(control_block)
+                    );
+                }
+                Some(tree.merkle_root())
+            };
+            assert_eq!(merkle_root.map(hex::encode).as_deref(), fixture.merkle_root);
+
+            let (output_key, _) = internal_key.tap_tweak(merkle_root);
+            assert_eq!(hex::encode(output_key.serialize()), fixture.output_key);
+            let script_pubkey = standard::p2tr(&output_key.serialize());
+            assert_eq!(hex::encode(&script_pubkey), fixture.script_pubkey);
+            assert_eq!(
+                Address::p2tr(&output_key.serialize(), Network::Bitcoin).to_string(),
+                fixture.address
+            );
+
+            // 다른 스크립트나 다른 출력 키로는 control block이 맞지 않는다
+            for leaf in fixture.leaves {
+                let control_block =
+                    ControlBlock::from_slice(&hex::decode(leaf.control_block).unwrap()).unwrap();
+                let script = hex::decode(leaf.script).unwrap();
+                assert!(control_block.verify(&output_key, &script));
+                assert!(!control_block.verify(&output_key, &[script.clone(), vec![0x51]].concat()));
+                assert!(!control_block.verify(&internal_key, &script));
+            }
+        }
+    }
+
+    /*리프 수가 홀수인 트리의 증명과 잘못된 입력 */
+    #[test]
+    fn test_tap_tree_shapes() {
+        let internal_key = PrivateKey::new(&[0x33; 32], Network::Bitcoin, true)
+            .unwrap()
+            .x_only_public_key();
+        for count in 1..=7u8 {
+            let tree =
+                TapTree::new((0..count).map(|i| TapLeaf::new(vec![0x51 + i])).collect()).unwrap();
+            let (output_key, _) = tree.output_key(&internal_key);
+            for (index, leaf) in tree.leaves().iter().enumerate() {
+                let control_block = tree.control_block(&internal_key, index).unwrap();
+                assert!(control_block.verify(&output_key, &leaf.script));
+            }
+            assert!(tree.control_block(&internal_key, count as usize).is_err());
+        }
+        // 리프 하나는 루트가 리프 해시, 경로가 비어 있다
+        let single = TapTree::new(vec![TapLeaf::new(vec![0x51])]).unwrap();
+        assert_eq!(
+            single.merkle_root(),
+            tap_leaf_hash(&[0x51], TAPROOT_LEAF_TAPSCRIPT)
+        );
+        assert!(single
+            .control_block(&internal_key, 0)
+            .unwrap()
+            .merkle_branch
+            .is_empty());
+
+        assert!(TapTree::new(vec![]).is_err());
+
+        // {A,{B,{C,D}}}: 균형 트리로는 만들 수 없는 모양
+        let leaves: Vec<TapLeaf> = (0..4u8).map(|i| TapLeaf::new(vec![0x51 + i])).collect();
+        let tree = TapTree::from_depths(
+            [1, 2, 3, 3]
+                .into_iter()
+                .zip(leaves.iter().cloned())
+                .collect(),
+        )
+        .unwrap();
+        let hashes: Vec<[u8; 32]> = leaves.iter().map(TapLeaf::leaf_hash).collect();
+        let cd = tap_branch_hash(&hashes[2], &hashes[3]);
+        let bcd = tap_branch_hash(&hashes[1], &cd);
+        assert_eq!(tree.merkle_root(), tap_branch_hash(&hashes[0], &bcd));
+        let (output_key, _) = tree.output_key(&internal_key);
+        for (index, leaf) in leaves.iter().enumerate() {
+            let control_block = tree.control_block(&internal_key, index).unwrap();
+            assert_eq!(control_block.merkle_branch.len(), [1, 2, 3, 3][index]);
+            assert!(control_block.verify(&output_key, &leaf.script));
+        }
+        // 빈 자리가 남거나 루트 위로 넘치는 깊이
+        for depths in [&[1, 2][..], &[1, 1, 1], &[0, 0], &[2, 1, 2], &[]] {
+            let leaves = depths
+                .iter()
+                .map(|&depth| (depth, TapLeaf::new(vec![0x51])))
+                .collect();
+            assert!(TapTree::from_depths(leaves).is_err(), "{depths:?}");
+        }
+        assert!(TapTree::new(vec![TapLeaf {
+            leaf_version: 0xc1,
+            script: vec![]
+        }])
+        .is_err());
+        assert_eq!(
+            ControlBlock::from_slice(&[0xc0; 34]),
+            Err(ScriptError::TaprootControlBlockSize)
+        );
+    }
+
+    /*키 경로 witness 오류 */
+    #[test]
+    fn test_taproot_key_spend_errors() {
+        let tx = Transaction::from_hex(fixtures::TAPROOT_KEY_SPEND_TX).unwrap();
+        let prevouts: Vec<TxOut> = deserialize_hex(fixtures::TAPROOT_KEY_SPEND_PREVOUTS).unwrap();
+        let fixture = &fixtures::TAPROOT_KEY_SPENDS[0];
+        let signature = hex::decode(fixture.signature).unwrap();
+        let index = fixture.input_index;
+
+        let with_witness = |witness: Vec<Vec<u8>>| {
+            let mut tx = tx.clone();
+            tx.tx_in[index].witness = witness;
+            verify_input_with_prevouts(&tx, index, &prevouts)
+        };
+        assert_eq!(with_witness(vec![signature.clone()]), Ok(()));
+        // annex가 있으면 서명 해시가 달라진다
+        assert_eq!(
+            with_witness(vec![signature.clone(), vec![0x50]]),
+            Err(ScriptError::SchnorrSig)
+        );
+        assert_eq!(
+            with_witness(vec![]),
+            Err(ScriptError::WitnessProgramWitnessEmpty)
+        );
+        assert_eq!(
+            with_witness(vec![signature[..63].to_vec()]),
+            Err(ScriptError::SchnorrSigSize)
+        );
+        let mut default_type = signature[..64].to_vec();
+        default_type.push(0x00);
+        assert_eq!(
+            with_witness(vec![default_type]),
+            Err(ScriptError::SchnorrSigHashType)
+        );
+        let mut corrupted = signature.clone();
+        corrupted[0] ^= 1;
+        assert_eq!(with_witness(vec![corrupted]), Err(ScriptError::SchnorrSig));
+        // 출력 키에 약속되지 않은 스크립트
+        assert_eq!(
+            with_witness(vec![vec![0x51], vec![0xc0; 33]]),
+            Err(ScriptError::WitnessProgramMismatch)
+        );
+    }
+
+    /*스크립트 경로: control block으로 약속을 확인하고 tapscript를 실행 */
+    #[test]
+    fn test_taproot_script_path() {
+        let tx = Transaction::from_hex(fixtures::TAPROOT_KEY_SPEND_TX).unwrap();
+        let mut prevouts: Vec<TxOut> =
+            deserialize_hex(fixtures::TAPROOT_KEY_SPEND_PREVOUTS).unwrap();
+        let internal_key = PrivateKey::new(&[0x33; 32], Network::Bitcoin, true)
+            .unwrap()
+            .x_only_public_key();
+        let keys: Vec<PrivateKey> = [[0x44; 32], [0x55; 32]]
+            .iter()
+            .map(|secret| PrivateKey::new(secret, Network::Bitcoin, true).unwrap())
+            .collect();
+        let push_key = |key: &PrivateKey| {
+            let mut script = vec![32];
+            script.extend_from_slice(&key.x_only_public_key().serialize());
+            script
+        };
+        // <k1> OP_CHECKSIG <k2> OP_CHECKSIGADD OP_2 OP_NUMEQUAL
+        let mut multisig = push_key(&keys[0]);
+        multisig.push(0xac);
+        multisig.extend(push_key(&keys[1]));
+        multisig.extend([0xba, 0x52, 0x9c]);
+        let leaves = vec![
+            (2, TapLeaf::new(vec![0x6a])),
+            (2, TapLeaf::new([push_key(&keys[0]), vec![0xac]].concat())),
+            (2, TapLeaf::new(multisig)),
+            // OP_IF OP_1 OP_ELSE OP_1 OP_ENDIF
+            (3, TapLeaf::new(vec![0x63, 0x51, 0x67, 0x51, 0x68])),
+            // OP_RESERVED (OP_SUCCESS80) OP_RETURN
+            (3, TapLeaf::new(vec![0x50, 0x6a])),
+        ];
+        let tree = TapTree::from_depths(leaves).unwrap();
+        let (output_key, _) = tree.output_key(&internal_key);
+        prevouts[0].pk_script = standard::p2tr(&output_key.serialize());
+
+        let sign = |tx: &Transaction, leaf_index: usize, key: &PrivateKey| {
+            let leaf = (tree.leaves()[leaf_index].leaf_hash(), u32::MAX);
+            let sighash = tx
+                .taproot_sighash(0, &prevouts, None, Some(leaf), 0x00)
+                .unwrap();
+            key.sign_schnorr(sighash, &[0; 32]).to_vec()
+        };
+        let spend = |leaf_index: usize, control_index: usize, stack: Vec<Vec<u8>>| {
+            let mut tx = tx.clone();
+            tx.tx_in[0].witness = stack;
+            tx.tx_in[0]
+                .witness
+                .push(tree.leaves()[leaf_index].script.clone());
+            tx.tx_in[0].witness.push(
+                tree.control_block(&internal_key, control_index)
+                    .unwrap()
+                    .serialize(),
+            );
+            verify_input_with_prevouts(&tx, 0, &prevouts)
+        };
+        // 약속되지 않은 스크립트, 실행할 수 없는 스크립트
+        assert_eq!(
+            spend(0, 1, vec![]),
+            Err(ScriptError::WitnessProgramMismatch)
+        );
+        assert_eq!(spend(0, 0, vec![]), Err(ScriptError::OpReturn));
+
+        let signature = sign(&tx, 1, &keys[0]);
+        assert_eq!(spend(1, 1, vec![signature.clone()]), Ok(()));
+        assert_eq!(spend(1, 1, vec![vec![]]), Err(ScriptError::EvalFalse));
+        assert_eq!(
+            spend(1, 1, vec![sign(&tx, 1, &keys[1])]),
+            Err(ScriptError::SchnorrSig)
+        );
+        // 다른 리프의 서명은 서명 해시가 다르다
+        assert_eq!(
+            spend(1, 1, vec![sign(&tx, 2, &keys[0])]),
+            Err(ScriptError::SchnorrSig)
+        );
+        assert_eq!(
+            spend(1, 1, vec![signature.clone(), vec![]]),
+            Err(ScriptError::CleanStack)
+        );
+
+        // 스택 맨 위의 서명부터 k1에 쓴다
+        let signatures = vec![sign(&tx, 2, &keys[1]), sign(&tx, 2, &keys[0])];
+        assert_eq!(spend(2, 2, signatures.clone()), Ok(()));
+        assert_eq!(
+            spend(2, 2, vec![vec![], signatures[1].clone()]),
+            Err(ScriptError::EvalFalse)
+        );
+
+        assert_eq!(spend(3, 3, vec![vec![]]), Ok(()));
+        assert_eq!(spend(3, 3, vec![vec![0x01]]), Ok(()));
+        assert_eq!(
+            spend(3, 3, vec![vec![0x02]]),
+            Err(ScriptError::TapscriptMinimalIf)
+        );
+
+        assert_eq!(spend(4, 4, vec![]), Ok(()));
+    }
+}
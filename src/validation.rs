@@ -0,0 +1,234 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::error::TxValidationError;
+use crate::{OutPoint, Transaction, MAX_BLOCK_WEIGHT, MAX_MONEY, WITNESS_SCALE_FACTOR};
+
+/*
+트랜잭션 검증 (UTXO나 체인 상태 없이 트랜잭션만 보고 하는 검사, Bitcoin Core의 CheckTransaction)
+- 구조: tx_in_count, tx_out_count, script_bytes, pk_script_bytes가 실제 목록, 스크립트 길이와 같은지
+- in, out 목록이 비어 있지 않은지
+- 크기: witness를 뺀 크기 * 4가 블록 weight 상한을 넘지 않는지
+- output 금액과 그 합이 2100만 BTC(MAX_MONEY)를 넘지 않는지
+- 같은 outpoint를 두 번 쓰지 않는지 (트랜잭션 안의 이중 지불)
+- 코인베이스는 서명 스크립트가 2~100bytes, 코인베이스가 아니면 빈 outpoint를 쓸 수 없다.
+이전 output 금액, 스크립트 실행, 코인베이스 보상 같은 검사는 UTXO가 있어야 하므로 여기서 하지 않는다.
+*/
+
+/// 코인베이스 서명 스크립트 길이 범위
+pub const COINBASE_SCRIPT_SIZE: RangeInclusive<usize> = 2..=100;
+
+impl Transaction {
+    pub fn check(&self) -> Result<(), TxValidationError> {
+        if self.tx_in_count != self.tx_in.len() {
+            return Err(TxValidationError::InputCountMismatch {
+                count: self.tx_in_count,
+                actual: self.tx_in.len(),
+            });
+        }
+        if self.tx_out_count != self.tx_out.len() {
+            return Err(TxValidationError::OutputCountMismatch {
+                count: self.tx_out_count,
+                actual: self.tx_out.len(),
+            });
+        }
+        if let Some(index) = self
+            .tx_in
+            .iter()
+            .position(|input| input.script_bytes != input.signature_script.len())
+        {
+            return Err(TxValidationError::InputScriptLengthMismatch(index));
+        }
+        if let Some(index) = self
+            .tx_out
+            .iter()
+            .position(|output| output.pk_script_bytes != output.pk_script.len())
+        {
+            return Err(TxValidationError::OutputScriptLengthMismatch(index));
+        }
+
+        if self.tx_in.is_empty() {
+            return Err(TxValidationError::NoInputs);
+        }
+        if self.tx_out.is_empty() {
+            return Err(TxValidationError::NoOutputs);
+        }
+        //witness는 블록에 따라 빠질 수 있으므로 witness 없는 크기로 제한한다.
+        let weight = self.base_size() * WITNESS_SCALE_FACTOR;
+        if weight > MAX_BLOCK_WEIGHT {
+            return Err(TxValidationError::Oversize(weight));
+        }
+
+        let mut total = 0u64;
+        for (index, output) in self.tx_out.iter().enumerate() {
+            if output.value > MAX_MONEY {
+                return Err(TxValidationError::OutputValueOutOfRange(index));
+            }
+            total += output.value;
+            if total > MAX_MONEY {
+                return Err(TxValidationError::TotalOutputValueOutOfRange);
+            }
+        }
+
+        let mut spent = HashSet::new();
+        if let Some(index) = self
+            .tx_in
+            .iter()
+            .position(|input| !spent.insert(input.previous_output))
+        {
+            return Err(TxValidationError::DuplicateInput(index));
+        }
+
+        if self.is_coinbase() {
+            let size = self.tx_in[0].signature_script.len();
+            if !COINBASE_SCRIPT_SIZE.contains(&size) {
+                return Err(TxValidationError::CoinbaseScriptSize(size));
+            }
+        } else if let Some(index) = self
+            .tx_in
+            .iter()
+            .position(|input| input.previous_output == OutPoint::null())
+        {
+            return Err(TxValidationError::NullPrevout(index));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{CoinbaseBuilder, TransactionBuilder};
+    use crate::{fixtures, Block};
+
+    /*실제 트랜잭션과 블록은 모두 통과 */
+    #[test]
+    fn test_check_valid() {
+        for hex in fixtures::TRANSACTIONS.iter().map(|fixture| fixture.hex) {
+            Transaction::from_hex(hex).unwrap().check().unwrap();
+        }
+        for hex in fixtures::SEGWIT_TRANSACTIONS
+            .iter()
+            .map(|fixture| fixture.hex)
+        {
+            Transaction::from_hex(hex).unwrap().check().unwrap();
+        }
+        for hex in [
+            fixtures::TESTNET_BLOCK_180480,
+            fixtures::TESTNET_SEGWIT_BLOCK,
+        ] {
+            let block = Block::from_hex(hex).unwrap();
+            assert!(block.txns[0].is_coinbase());
+            for tx in &block.txns {
+                tx.check().unwrap();
+            }
+        }
+        CoinbaseBuilder::new(1)
+            .reward_to(vec![0x51])
+            .build()
+            .unwrap()
+            .check()
+            .unwrap();
+    }
+
+    /*규칙마다 하나씩 어긴 트랜잭션 */
+    #[test]
+    fn test_check_rules() {
+        let valid = TransactionBuilder::new()
+            .add_input(OutPoint {
+                txid: [1; 32],
+                vout: 0,
+            })
+            .add_output(vec![0x51], 1)
+            .build()
+            .unwrap();
+        let check = |change: &dyn Fn(&mut Transaction)| {
+            let mut tx = valid.clone();
+            change(&mut tx);
+            tx.check()
+        };
+
+        assert_eq!(
+            check(&|tx| tx.tx_in_count = 2),
+            Err(TxValidationError::InputCountMismatch {
+                count: 2,
+                actual: 1,
+            })
+        );
+        assert_eq!(
+            check(&|tx| tx.tx_out_count = 0),
+            Err(TxValidationError::OutputCountMismatch {
+                count: 0,
+                actual: 1,
+            })
+        );
+        assert_eq!(
+            check(&|tx| tx.tx_in[0].script_bytes = 1),
+            Err(TxValidationError::InputScriptLengthMismatch(0))
+        );
+        assert_eq!(
+            check(&|tx| tx.tx_out[0].pk_script.push(0x87)),
+            Err(TxValidationError::OutputScriptLengthMismatch(0))
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_in.clear();
+                tx.tx_in_count = 0;
+            }),
+            Err(TxValidationError::NoInputs)
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_out.clear();
+                tx.tx_out_count = 0;
+            }),
+            Err(TxValidationError::NoOutputs)
+        );
+        assert!(matches!(
+            check(&|tx| {
+                tx.tx_out[0].pk_script = vec![0; MAX_BLOCK_WEIGHT / WITNESS_SCALE_FACTOR];
+                tx.tx_out[0].pk_script_bytes = tx.tx_out[0].pk_script.len();
+            }),
+            Err(TxValidationError::Oversize(_))
+        ));
+        assert_eq!(
+            check(&|tx| tx.tx_out[0].value = MAX_MONEY + 1),
+            Err(TxValidationError::OutputValueOutOfRange(0))
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_out[0].value = MAX_MONEY;
+                tx.tx_out.push(tx.tx_out[0].clone());
+                tx.tx_out_count = 2;
+            }),
+            Err(TxValidationError::TotalOutputValueOutOfRange)
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_in.push(tx.tx_in[0].clone());
+                tx.tx_in_count = 2;
+            }),
+            Err(TxValidationError::DuplicateInput(1))
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_in.push(tx.tx_in[0].clone());
+                tx.tx_in[1].previous_output = OutPoint::null();
+                tx.tx_in_count = 2;
+            }),
+            Err(TxValidationError::NullPrevout(1))
+        );
+        assert_eq!(
+            check(&|tx| tx.tx_in[0].previous_output = OutPoint::null()),
+            Err(TxValidationError::CoinbaseScriptSize(0))
+        );
+        assert_eq!(
+            check(&|tx| {
+                tx.tx_in[0].previous_output = OutPoint::null();
+                tx.tx_in[0].signature_script = vec![0; 101];
+                tx.tx_in[0].script_bytes = 101;
+            }),
+            Err(TxValidationError::CoinbaseScriptSize(101))
+        );
+    }
+}